@@ -1,17 +1,37 @@
 mod test;
+pub mod plural;
 
 /// This macro genrerates functions for a given enum that return language variants of a String.
 /// The generatiated functions can take parameters that implement `std::fmt::Display`.
 ///
 /// # Notes
-/// - The first language variant is considered the default value.
+/// - The first language variant is considered the default value. Marking an entry with `default` like `default English: "..."` makes its language the default value of the key instead. A key can have only one entry marked with `default`.
 /// - If a language variant is not provided for a field, the default value is used.
+/// - Setting the `LANGUAGE_ATLAS_DEFAULT` environment variable to a variant name (e.g. `LANGUAGE_ATLAS_DEFAULT=German`) at compile time uses that language as the default value for all fields that provide it and have no entry marked with `default`. The value has to be the exact variant name, language codes like `de` are not understood. With the `Languages` header a value that names none of its languages fails to compile. **Without the `Languages` header such a value is silently ignored**, and keys fall back as if the variable was unset, e.g. to English. A build that must never fall back to another language has to use the `Languages` header.
 /// - If no language string is provided for a field, a deprecated function returning “ToDo!” is generated. The function signature stays the same.
 /// - Parameter functions return a `String` type, while non-parameter functions return a `&'static str` type.
-/// - The types of parameters are optional. If no types are provided, the parameters have to implement the `Display` trait.
+/// - The types of parameters are optional. If no types are provided, the parameters have to implement the `Display` trait or [`AtlasFormat`], which renders types without a `Display` implementation the same way in every string. Types that implement `Display` always use their `Display` implementation.
+/// - Parameters without a type accept `Lazy(|| ...)`, which is only computed if the string of the selected language uses the placeholder, and at most once.
+/// - Strings of parameter functions are `format!` strings, literal braces have to be escaped as `{{` and `}}`. Strings of non-parameter functions are returned verbatim. Every placeholder has to name a parameter of the key, otherwise the key fails to compile with an error naming the key and the language.
+/// - With `LanguageEnum: Language, Schema: path::Trait` the functions implement a trait declared with `generate_language_schema!` instead of being inherent methods. Missing keys or mismatching signatures fail to compile. Plural, `ordinal` and `select` fields are declared in the schema without their mode and with typed parameters, e.g. `files(count: u32)`.
+/// - `Missing: KeyName` returns the key name in brackets (`[greeting]`) for languages without a string, `Missing: Marker` returns it as a pseudo-localization marker (`⟦greeting⟧`) and `Missing: Empty` returns an empty string. The default policy `Missing: Default` falls back to the default value.
+/// - `Missing: Strict` requires a string for every language and forbids keys without strings (`{ }`), both fail to compile. Plural and select fields also need an `other` string or a string without category for every language. With the `default-lang-only` feature it falls back to the default value instead.
+/// - `Missing: { dev: KeyName, staging: Marker, prod: Default }` selects the policy with the `LANGUAGE_ATLAS_ENV` environment variable at compile time. If the variable is unset or matches no entry, the default value is used.
+/// - `Hook: path::to::function` calls `function(key, &language)` every time a function is called, e.g. to count how often each key is rendered per language. The hook has the signature `fn(&'static str, &Language)`.
+/// - With the `default-lang-only` feature only the strings of the default language are compiled. All other languages use the fallback of the missing string policy. The default language is chosen by the `default` marker, the `Default` header or the order of the entries, `LANGUAGE_ATLAS_DEFAULT` has no effect with this feature.
+/// - `Languages: [English, German, Spanish]` lists all languages and emits a deprecation warning for every key and listed language without a string, e.g. ``Key `farewell` has no `Spanish` string.``. `#[allow(deprecated)]` on the invocation silences the warnings, `#![deny(deprecated)]` turns them into errors.
+/// - `Languages: pub enum [English, German, Spanish]` (or `enum [...]`) also generates the language enum with the variants in this order. It derives `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq` and `Hash`.
+/// - Languages can be annotated with their code, English name and native name, e.g. `Languages: [English("en", "English", "English"), Spanish("es", "Spanish", "Español")]`. This generates `Language::code()`, `english_name()` and `native_name()`. Either all or none of the languages have to be annotated.
+/// - `Coverage: release_coverage { English: 100, German: 90 }` generates a `#[test]` named `release_coverage` that fails if a listed language provides less than the given percentage of the keys. Languages that are not listed are not checked.
+/// - `Reference: key_reference` generates `Language::key_reference()`, which returns a Markdown table of all keys with their parameters and default strings, plural and select strings are listed with their category, e.g. to write a reference document for translators from a test or build script.
+/// - `Default: English` uses the English string as the default value of every key that provides one, regardless of the order of the entries. The default value of a key is the entry marked with `default`, then the language of `LANGUAGE_ATLAS_DEFAULT`, then the `Default` language and then the first entry.
+/// - `Fallback: [PortugueseBrazil -> Portuguese -> English, SwissGerman -> German]` declares fallback chains. A language without a string uses the string of the first language in its chain that provides one before the missing string policy applies.
+/// - Header options are separated by commas and follow `LanguageEnum` in any order. Each option can be given once.
+/// - Keys that are Rust keywords or clash with other methods can be written as raw identifiers (`r#type`) or renamed with `type @fn(type_label) { ... }`. In both cases the key name `type` without `r#` is used for the hook, the missing string policy, the coverage test and the key reference. The warnings of the `Languages` header show raw identifiers with `r#`.
 ///
 /// # Example
-/// ```rust
+#[cfg_attr(not(feature = "default-lang-only"), doc = "```rust")]
+#[cfg_attr(feature = "default-lang-only", doc = "```rust,ignore")]
 /// use language_atlas::generate_language_functions;
 ///
 /// enum Language {
@@ -65,6 +85,227 @@ mod test;
 ///     assert_eq!(lang.dummy(), "ToDo!");
 /// }
 /// ```
+/// # Plurals
+///
+/// Fields can provide a string per CLDR plural category with `Language.category: "..."`.
+/// The first parameter is the count, it has to be an integer. The language enum has to implement
+/// [`plural::PluralRules`] to select the plural rule of each language.
+/// Languages without plural forms can provide a single string. If a category is missing, the `other` string of the language is used.
+/// A language without a matching string and without `other` is treated like a language without strings: it uses its fallback chain and then the missing string policy, e.g. the string of the default language with `Missing: Default` or `[files]` with `Missing: KeyName`.
+/// Numbers like `Language.0: "..."` match an exact count and take precedence over the categories.
+/// Fields that only use numbers and `other` do not need [`plural::PluralRules`].
+/// Use `.0` and `.1` for a simple zero/one/other branch. `zero` is the CLDR category, which only exists in rules
+/// like [`plural::PluralRule::Latvian`] and matches more counts than `0` there. `English.zero` never matches
+/// because [`plural::PluralRule::English`] has no `zero` category.
+/// Fields marked with `ordinal` like `place ordinal(n: u32) { ... }` select the category with the ordinal
+/// rules of [`plural::OrdinalRules`] instead, e.g. `1st`, `2nd`, `3rd` and `4th` in English.
+///
+/// Fields marked with `select` like `invited select(gender: Gender, name: &str) { ... }` branch on the variants
+/// of their first parameter instead, e.g. `English.Female: "..."`. The parameter has to be an enum.
+/// Variants without a branch use the `other` string of the language, or the fallback chain and the missing string policy if it has none.
+///
+#[cfg_attr(not(feature = "default-lang-only"), doc = "```rust")]
+#[cfg_attr(feature = "default-lang-only", doc = "```rust,ignore")]
+/// use language_atlas::generate_language_functions;
+/// use language_atlas::plural::{PluralRule, PluralRules};
+///
+/// enum Language {
+///     English,
+///     Polish,
+///     Japanese,
+/// }
+///
+/// impl PluralRules for Language {
+///     fn plural_rule(&self) -> PluralRule {
+///         match self {
+///             Language::English => PluralRule::English,
+///             Language::Polish => PluralRule::Polish,
+///             Language::Japanese => PluralRule::NoPlural,
+///         }
+///     }
+/// }
+///
+/// generate_language_functions! {
+///     LanguageEnum: Language
+///     files(count: u32) {
+///         English.one:   "{count} file"
+///         English.other: "{count} files"
+///         Polish.one:    "{count} plik"
+///         Polish.few:    "{count} pliki"
+///         Polish.many:   "{count} plików"
+///         Japanese:      "{count}個のファイル"
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(Language::English.files(1), "1 file");
+///     assert_eq!(Language::English.files(3), "3 files");
+///     assert_eq!(Language::Polish.files(3), "3 pliki");
+///     assert_eq!(Language::Polish.files(5), "5 plików");
+///     assert_eq!(Language::Japanese.files(5), "5個のファイル");
+/// }
+/// ```
+///
+/// # Compile errors
+///
+/// The strings of parameter functions are checked by `format!` at compile time.
+/// An un-escaped brace in any language fails to compile instead of panicking at runtime.
+///
+#[cfg_attr(not(feature = "default-lang-only"), doc = "```rust,compile_fail")]
+#[cfg_attr(feature = "default-lang-only", doc = "```rust,ignore")]
+/// use language_atlas::generate_language_functions;
+///
+/// enum Language {
+///     English,
+///     German,
+/// }
+///
+/// generate_language_functions! {
+///     LanguageEnum: Language
+///     braces(name) {
+///         English: "{{{name}}}"
+///         German:  "{{name}}}"
+///     }
+/// }
+/// ```
+///
+/// Every placeholder of a parameter function, including named widths and precisions like `{name:>width$}`,
+/// has to name a parameter of the key. Otherwise the key fails to compile with an error naming the key and
+/// the language, also if the placeholder names another binding in scope, e.g. a constant.
+/// The check covers all languages, also with the `default-lang-only` feature.
+///
+/// ```rust,compile_fail
+/// use language_atlas::generate_language_functions;
+///
+/// enum Language {
+///     English,
+///     German,
+/// }
+///
+/// generate_language_functions! {
+///     LanguageEnum: Language
+///     farewell(name) {
+///         English: "Goodbye, {name}"
+///         German:  "Auf Wiedersehen, {nmae}"
+///     }
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// use language_atlas::generate_language_functions;
+///
+/// enum Language {
+///     English,
+///     German,
+/// }
+///
+/// const NAME: &str = "ACME";
+///
+/// generate_language_functions! {
+///     LanguageEnum: Language
+///     farewell(name) {
+///         English: "Goodbye, {name}"
+///         German:  "Auf Wiedersehen, {NAME}"
+///     }
+/// }
+/// ```
+///
+/// With `Missing: Strict` a key without a string for every language fails to compile.
+///
+#[cfg_attr(not(feature = "default-lang-only"), doc = "```rust,compile_fail")]
+#[cfg_attr(feature = "default-lang-only", doc = "```rust,ignore")]
+/// use language_atlas::generate_language_functions;
+///
+/// enum Language {
+///     English,
+///     German,
+/// }
+///
+/// generate_language_functions! {
+///     LanguageEnum: Language, Missing: Strict
+///     greeting {
+///         English: "Hello"
+///     }
+/// }
+/// ```
+///
+/// With `Missing: Strict` a plural or select field fails to compile if a language has neither an `other` string
+/// nor a string without category.
+///
+/// ```rust,compile_fail
+/// use language_atlas::generate_language_functions;
+///
+/// enum Language {
+///     English,
+///     German,
+/// }
+///
+/// generate_language_functions! {
+///     LanguageEnum: Language, Missing: Strict
+///     files(count: u32) {
+///         English.1:     "One file"
+///         English.other: "{count} files"
+///         German.1:      "Eine Datei"
+///     }
+/// }
+/// ```
+///
+/// A key with more than one entry marked with `default` fails to compile.
+///
+/// ```rust,compile_fail
+/// use language_atlas::generate_language_functions;
+///
+/// enum Language {
+///     English,
+///     German,
+/// }
+///
+/// generate_language_functions! {
+///     LanguageEnum: Language
+///     greeting {
+///         default English: "Hello"
+///         default German:  "Hallo"
+///     }
+/// }
+/// ```
+///
+/// With `Languages` and `#![deny(deprecated)]` a key without a string for a listed language fails to compile.
+///
+/// ```rust,compile_fail
+/// #![deny(deprecated)]
+/// use language_atlas::generate_language_functions;
+///
+/// enum Language {
+///     English,
+///     Spanish,
+/// }
+///
+/// generate_language_functions! {
+///     LanguageEnum: Language, Languages: [English, Spanish]
+///     greeting {
+///         English: "Hello"
+///     }
+/// }
+/// ```
+///
+/// A header option given twice fails to compile.
+///
+/// ```rust,compile_fail
+/// use language_atlas::generate_language_functions;
+///
+/// enum Language {
+///     English,
+///     German,
+/// }
+///
+/// generate_language_functions! {
+///     LanguageEnum: Language, Missing: KeyName, Missing: Empty
+///     greeting {
+///         English: "Hello"
+///     }
+/// }
+/// ```
+///
 /// # Expands to
 ///
 /// ```rust
@@ -81,20 +322,35 @@ mod test;
 ///         match self {
 ///             Language::Spanish => "Hola",
 ///             Language::French => "Bonjour",
-///             Language::English | _ => "Hello",
+///             Language::English => "Hello",
+///             _ => match option_env!("LANGUAGE_ATLAS_DEFAULT") {
+///                 Some("Spanish") => "Hola",
+///                 Some("French") => "Bonjour",
+///                 _ => "Hello",
+///             },
 ///         }
 ///     }
-///     pub fn farewell<name: std::fmt::Display>(&self, name: name) -> String {
+///     pub fn farewell<name: language_atlas::AtlasFormat>(&self, name: name) -> String {
+///         let name = language_atlas::__AtlasDisplay(&name);
 ///         match self {
 ///             Language::Spanish => format!("Adiós, {name}"),
 ///             Language::French => format!("Au revoir, {name}"),
-///             Language::English | _ => format!("Goodbye, {name}"),
+///             Language::English => format!("Goodbye, {name}"),
+///             _ => match option_env!("LANGUAGE_ATLAS_DEFAULT") {
+///                 Some("Spanish") => format!("Adiós, {name}"),
+///                 Some("French") => format!("Au revoir, {name}"),
+///                 _ => format!("Goodbye, {name}"),
+///             },
 ///         }
 ///     }
 ///     pub fn date(&self, a: u8, b: u8, c: u16) -> String {
 ///         match self {
 ///             Language::English => format!("{b}/{a}/{c}"),
-///             Language::French | _ => format!("{a}/{b}/{c}"),
+///             Language::French => format!("{a}/{b}/{c}"),
+///             _ => match option_env!("LANGUAGE_ATLAS_DEFAULT") {
+///                 Some("English") => format!("{b}/{a}/{c}"),
+///                 _ => format!("{a}/{b}/{c}"),
+///             },
 ///         }
 ///     }
 ///     #[deprecated(note = "No language string provided for this field. Defaulting to 'ToDo!'")]
@@ -105,89 +361,1304 @@ mod test;
 /// ```
 #[macro_export]
 macro_rules! generate_language_functions {
-    (
+    (LanguageEnum: $enum_name:ident $($rest:tt)*) => {
+        generate_language_functions! { @header [$enum_name] [] [] [] [] [] [] [] [] $($rest)* }
+    };
+
+    (@header $enum_name:tt [] $fallback:tt $schema:tt $missing:tt $hook:tt $languages:tt $coverage:tt $reference:tt , Default: $default:ident $($rest:tt)*) => {
+        generate_language_functions! { @header $enum_name [, Default: $default] $fallback $schema $missing $hook $languages $coverage $reference $($rest)* }
+    };
+    (@header $enum_name:tt $default:tt [] $schema:tt $missing:tt $hook:tt $languages:tt $coverage:tt $reference:tt , Fallback: [$($fallback:tt)*] $($rest:tt)*) => {
+        generate_language_functions! { @header $enum_name $default [, Fallback: [$($fallback)*]] $schema $missing $hook $languages $coverage $reference $($rest)* }
+    };
+    (@header $enum_name:tt $default:tt $fallback:tt [] $missing:tt $hook:tt $languages:tt $coverage:tt $reference:tt , Schema: $segment:ident $($rest:tt)*) => {
+        generate_language_functions! { @header_path Schema [$segment] [$enum_name $default $fallback [] $missing $hook $languages $coverage $reference] $($rest)* }
+    };
+    (@header $enum_name:tt $default:tt $fallback:tt $schema:tt [] $hook:tt $languages:tt $coverage:tt $reference:tt , Missing: $missing:tt $($rest:tt)*) => {
+        generate_language_functions! { @header $enum_name $default $fallback $schema [, Missing: $missing] $hook $languages $coverage $reference $($rest)* }
+    };
+    (@header $enum_name:tt $default:tt $fallback:tt $schema:tt $missing:tt [] $languages:tt $coverage:tt $reference:tt , Hook: $segment:ident $($rest:tt)*) => {
+        generate_language_functions! { @header_path Hook [$segment] [$enum_name $default $fallback $schema $missing [] $languages $coverage $reference] $($rest)* }
+    };
+    (@header $enum_name:tt $default:tt $fallback:tt $schema:tt $missing:tt $hook:tt [] $coverage:tt $reference:tt , Languages: $($languages_kind:ident)* [$($languages:tt)*] $($rest:tt)*) => {
+        generate_language_functions! { @header $enum_name $default $fallback $schema $missing $hook [, Languages: $($languages_kind)* [$($languages)*]] $coverage $reference $($rest)* }
+    };
+    (@header $enum_name:tt $default:tt $fallback:tt $schema:tt $missing:tt $hook:tt $languages:tt [] $reference:tt , Coverage: $coverage_test:ident $coverage:tt $($rest:tt)*) => {
+        generate_language_functions! { @header $enum_name $default $fallback $schema $missing $hook $languages [, Coverage: $coverage_test $coverage] $reference $($rest)* }
+    };
+    (@header $enum_name:tt $default:tt $fallback:tt $schema:tt $missing:tt $hook:tt $languages:tt $coverage:tt [] , Reference: $reference:ident $($rest:tt)*) => {
+        generate_language_functions! { @header $enum_name $default $fallback $schema $missing $hook $languages $coverage [, Reference: $reference] $($rest)* }
+    };
+    (@header $enum_name:tt $default:tt $fallback:tt $schema:tt $missing:tt $hook:tt $languages:tt $coverage:tt $reference:tt , $option:ident: $($rest:tt)*) => {
+        compile_error!(concat!(
+            "Header option `", stringify!($option), "` is unknown or given twice. ",
+            "Expected each of `Default`, `Fallback`, `Schema`, `Missing`, `Hook`, `Languages`, `Coverage` and `Reference` at most once."
+        ));
+    };
+    (@header_path $option:ident [$($path:tt)*] $headers:tt :: $segment:ident $($rest:tt)*) => {
+        generate_language_functions! { @header_path $option [$($path)* :: $segment] $headers $($rest)* }
+    };
+    (@header_path Schema [$($path:tt)*] [$enum_name:tt $default:tt $fallback:tt [] $missing:tt $hook:tt $languages:tt $coverage:tt $reference:tt] $($rest:tt)*) => {
+        generate_language_functions! { @header $enum_name $default $fallback [, Schema: $($path)*] $missing $hook $languages $coverage $reference $($rest)* }
+    };
+    (@header_path Hook [$($path:tt)*] [$enum_name:tt $default:tt $fallback:tt $schema:tt $missing:tt [] $languages:tt $coverage:tt $reference:tt] $($rest:tt)*) => {
+        generate_language_functions! { @header $enum_name $default $fallback $schema $missing [, Hook: $($path)*] $languages $coverage $reference $($rest)* }
+    };
+    (@header [$enum_name:ident] [$($default:tt)*] [$($fallback:tt)*] [$($schema:tt)*] [$($missing:tt)*] [$($hook:tt)*] [$($languages:tt)*] [$($coverage:tt)*] [$($reference:tt)*] $($fields:tt)*) => {
+        generate_language_functions! { @parsed LanguageEnum: $enum_name $($default)* $($fallback)* $($schema)* $($missing)* $($hook)* $($languages)* $($coverage)* $($reference)* $($fields)* }
+    };
+
+    (@parsed
         LanguageEnum: $enum_name:ident
-        $($field:ident $( ( $($args:ident $(: $args_type:ty )? ),+ ) )? {
-            $($lang:ident: $value:expr $(,)? )*
+        $(, Default: $default:ident )?
+        $(, Fallback: [$($fallback:tt)*] )?
+        $(, Schema: $($schema:ident)::+ )?
+        $(, Missing: $missing:tt )?
+        $(, Hook: $($hook:ident)::+ )?
+        $(, Languages: $($languages_kind:ident)* [$($languages:ident $( ( $($language_meta:tt)* ) )?),+ $(,)?] )?
+        $(, Coverage: $coverage_test:ident $coverage:tt )?
+        $(, Reference: $reference:ident )?
+        $($field:ident $(@fn($method:ident))? $($kind:ident)? $( ( $($args:ident $(: $args_type:ty )? ),+ ) )? {
+            $($lang:ident $($marked_lang:ident)? $(. $cat:tt)?: $value:expr $(,)? )*
         })*
     ) => {
+        $(
+            generate_language_functions!(@enum [$($languages_kind)*] $enum_name [$($languages),+]);
+            generate_language_functions!(@metadata $enum_name [$($languages $( ( $($language_meta)* ) )?),+]);
+            const _: () = assert!(
+                $crate::__is_language(option_env!("LANGUAGE_ATLAS_DEFAULT"), &[$(stringify!($languages)),+]),
+                concat!("`LANGUAGE_ATLAS_DEFAULT` does not name a language of the `Languages` header. Expected one of", $(" `", stringify!($languages), "`",)+ ".")
+            );
+        )?
+        generate_language_functions! { @impl
+            [$( $($schema)::+ )?]
+            [missing: [$($missing)?] hook: [$( $($hook)::+ )?] languages: [$( $($languages),+ )?] coverage: [$($coverage_test $coverage)?] default: [$($default)?] fallback: [$($($fallback)*)?]]
+            $enum_name
+            $({ $field [$($method)?] [$($kind)? $( ( $($args $($args_type)? ),* ) )?] { $($lang $($marked_lang)? $(. $cat)?: $value,)* } })*
+        }
+        generate_language_functions! { @reference [$($reference)?] [$($default)?] $enum_name
+            $({ $field [$( ( $($args $($args_type)? ),* ) )?] { $($lang $($marked_lang)? $(. $cat)?: $value,)* } })*
+        }
+    };
+
+    (@reference [] $default:tt $enum_name:ident $($fields:tt)*) => { };
+    (@reference [$reference:ident] [$($default:ident)?] $enum_name:ident $({ $key:ident [$( ( $($parameter:ident $($parameter_type:ty)?),* ) )?] {
+        $($lang:ident $($marked_lang:ident)? $(. $cat:tt)?: $value:expr,)*
+    } })*) => {
+        #[allow(dead_code)]
+        impl $enum_name {
+            /// Returns a Markdown table of all keys with their parameters and default strings.
+            pub fn $reference() -> String {
+                let mut reference = String::from("| Key | Parameters | Default |\n| --- | --- | --- |\n");
+                let default: Option<&str> = [$(stringify!($default))?].first().copied();
+                $(
+                    reference.push_str(&$crate::__reference_row(
+                        $crate::__key_name(stringify!($key)),
+                        &[$($( generate_language_functions!(@reference_parameter $parameter $($parameter_type)?) ),*)?],
+                        &[$( generate_language_functions!(@reference_entry [$lang $($marked_lang)?] [$($cat)?] $value) ),*],
+                        default,
+                        option_env!("LANGUAGE_ATLAS_DEFAULT"),
+                    ));
+                )*
+                reference
+            }
+        }
+    };
+
+    (@reference_parameter $parameter:ident) => { stringify!($parameter) };
+    (@reference_parameter $parameter:ident $parameter_type:ty) => { concat!(stringify!($parameter), ": ", stringify!($parameter_type)) };
+    (@reference_entry [$lang:ident] [$($cat:tt)?] $value:expr) => { (stringify!($lang), false, concat!($(stringify!($cat))?), $value) };
+    (@reference_entry [$marker:ident $lang:ident] [$($cat:tt)?] $value:expr) => { (stringify!($lang), true, concat!($(stringify!($cat))?), $value) };
+
+    (@enum [] $enum_name:ident $languages:tt) => { };
+    (@enum [enum] $enum_name:ident [$($language:ident),+]) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        enum $enum_name {
+            $( $language, )+
+        }
+    };
+    (@enum [pub enum] $enum_name:ident [$($language:ident),+]) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $enum_name {
+            $( $language, )+
+        }
+    };
+    (@enum [$($kind:ident)*] $enum_name:ident $languages:tt) => {
+        compile_error!(concat!("Unknown language enum declaration `", stringify!($($kind)*), "`. Expected `enum` or `pub enum`."));
+    };
+
+    (@metadata $enum_name:ident [$($language:ident),+]) => { };
+    (@metadata $enum_name:ident [$($language:ident ($code:literal, $english_name:literal, $native_name:literal $(,)?)),+]) => {
+        #[allow(dead_code)]
+        impl $enum_name {
+            /// Returns the language code, e.g. `"es"`.
+            pub fn code(&self) -> &'static str {
+                match self {
+                    $( $enum_name::$language => $code, )+
+                }
+            }
+
+            /// Returns the English name of the language, e.g. `"Spanish"`.
+            pub fn english_name(&self) -> &'static str {
+                match self {
+                    $( $enum_name::$language => $english_name, )+
+                }
+            }
+
+            /// Returns the name of the language in the language itself, e.g. `"Español"`.
+            pub fn native_name(&self) -> &'static str {
+                match self {
+                    $( $enum_name::$language => $native_name, )+
+                }
+            }
+        }
+    };
+    (@metadata $enum_name:ident $languages:tt) => {
+        compile_error!("Either all or none of the languages need metadata `(code, English name, native name)`.");
+    };
+
+    (@impl [] $config:tt $enum_name:ident $({ $($field_tokens:tt)* })*) => {
         #[allow(unreachable_patterns)]
         #[allow(non_camel_case_types)]
         impl $enum_name {
             $(
-                generate_language_functions!(@field_impl $enum_name $field $( ( $($args $($args_type)? ),* ) )? { $($lang: $value,)* } );
+                generate_language_functions!(@field [pub] [#[deprecated(note = "No language string provided for this field. Defaulting to 'ToDo!'")]] $config $enum_name $($field_tokens)* );
+            )*
+        }
+        generate_language_functions!(@coverage $config $enum_name $({ $($field_tokens)* })*);
+    };
+
+    (@impl [$($schema:ident)::+] $config:tt $enum_name:ident $({ $($field_tokens:tt)* })*) => {
+        #[allow(unreachable_patterns)]
+        #[allow(non_camel_case_types)]
+        impl $($schema)::+ for $enum_name {
+            $(
+                generate_language_functions!(@field [] [] $config $enum_name $($field_tokens)* );
             )*
         }
+        generate_language_functions!(@coverage $config $enum_name $({ $($field_tokens)* })*);
+    };
+
+    (@coverage [missing: $missing:tt hook: $hook:tt languages: [] coverage: $coverage:tt default: $default:tt fallback: $fallback:tt] $enum_name:ident $($field_tokens:tt)*) => {
+        generate_language_functions!(@coverage_test $coverage $enum_name $($field_tokens)*);
+    };
+    (@coverage [missing: $missing:tt hook: $hook:tt languages: $languages:tt coverage: $coverage:tt default: $default:tt fallback: $fallback:tt] $enum_name:ident $({
+        $key:ident $method:tt $signature:tt { $($lang:ident $($marked_lang:ident)? $(. $cat:tt)?: $value:expr,)* }
+    })*) => {
+        $( generate_language_functions!(@missing_warnings ($) $key $languages { $([$lang $($marked_lang)?])* }); )*
+        generate_language_functions!(@coverage_test $coverage $enum_name $({ $key $method $signature { $($lang $($marked_lang)? $(. $cat)?: $value,)* } })*);
+    };
+
+    // Emits a deprecation warning for every language of the `Languages` header that the key does not provide.
+    (@missing_warnings ($d:tt) $key:ident [$($language:ident),+] { $([$lang:ident $($marked_lang:ident)?])* }) => {
+        const _: () = {
+            macro_rules! __language_atlas_missing {
+                $(
+                    ($lang $d($d rest:ident)*) => { __language_atlas_missing! { $d($d rest)* } };
+                    $( ($marked_lang $d($d rest:ident)*) => { __language_atlas_missing! { $d($d rest)* } }; )?
+                )*
+                ($d language:ident $d($d rest:ident)*) => {
+                    {
+                        #[deprecated = concat!("Key `", stringify!($key), "` has no `", stringify!($d language), "` string. It falls back with the missing string policy.")]
+                        const fn missing_translation() {}
+                        missing_translation();
+                    }
+                    __language_atlas_missing! { $d($d rest)* }
+                };
+                () => { };
+            }
+            __language_atlas_missing! { $($language)+ }
+        };
+    };
+
+    (@coverage_test [] $enum_name:ident $($field_tokens:tt)*) => { };
+    (@coverage_test [$test:ident { $($language:ident: $percent:literal),+ $(,)? }] $enum_name:ident $({
+        $key:ident $method:tt $signature:tt { $($lang:ident $($marked_lang:ident)? $(. $cat:tt)?: $value:expr,)* }
+    })*) => {
+        #[cfg(test)]
+        #[test]
+        #[allow(unreachable_patterns)]
+        fn $test() {
+            let keys: &[(&str, fn(&$enum_name) -> bool)] = &[
+                $( ($crate::__key_name(stringify!($key)), |lang: &$enum_name| match lang { $( generate_language_functions!(@entry_language $enum_name [$lang $($marked_lang)?]) => true, )* _ => false }), )*
+            ];
+            $(
+                let missing: Vec<&str> = keys.iter().filter(|(_, provides)| !provides(&$enum_name::$language)).map(|(key, _)| *key).collect();
+                if let Err(message) = $crate::__check_coverage(stringify!($language), keys.len(), &missing, $percent) {
+                    panic!("{}", message);
+                }
+            )+
+        }
+    };
+
+    (@entry_language $enum_name:ident [$lang:ident]) => { $enum_name::$lang };
+    (@entry_language $enum_name:ident [$marker:ident $lang:ident]) => { $enum_name::$lang };
+
+    (@field $vis:tt $placeholder_attr:tt $config:tt $enum_name:ident $key:ident $method:tt [$($signature:tt)*] { $($entries:tt)* }) => {
+        generate_language_functions!(@default [$vis $placeholder_attr $config $enum_name $key $method $($signature)*] [] [] $($entries)*);
+    };
+
+    (@default [$vis:tt $placeholder_attr:tt $config:tt $enum_name:ident $key:ident $($field:tt)*] [$($default:tt)+] $entries:tt default $lang:ident $(. $cat:tt)?: $value:expr, $($rest:tt)*) => {
+        compile_error!(concat!("Key `", stringify!($key), "` has more than one entry marked with `default`."));
+    };
+    (@default $field:tt [$($default:tt)*] $entries:tt default $lang:ident $(. $cat:tt)?: $value:expr, $($rest:tt)*) => {
+        generate_language_functions!(@default $field [$($default)* $lang $(. $cat)?: $value,] $entries $($rest)*);
+    };
+    (@default $field:tt $default:tt $entries:tt $marker:ident $lang:ident $(. $cat:tt)?: $value:expr, $($rest:tt)*) => {
+        compile_error!(concat!("Unknown marker `", stringify!($marker), "` before `", stringify!($lang), "`. Expected `default`."));
+    };
+    (@default $field:tt $default:tt [$($entries:tt)*] $lang:ident $(. $cat:tt)?: $value:expr, $($rest:tt)*) => {
+        generate_language_functions!(@default $field $default [$($entries)* $lang $(. $cat)?: $value,] $($rest)*);
+    };
+    (@default [$vis:tt $placeholder_attr:tt [missing: $missing:tt hook: $hook:tt languages: $languages:tt coverage: $coverage:tt default: $header_default:tt fallback: $fallback:tt] $($field:tt)*] [$($default:tt)+] [$($entries:tt)*]) => {
+        generate_language_functions!(@field_impl $vis $placeholder_attr [missing: $missing hook: $hook languages: $languages coverage: $coverage default: [@marked] fallback: $fallback] $($field)* { $($default)* $($entries)* });
+    };
+    (@default [$($field:tt)*] [$($default:tt)*] [$($entries:tt)*]) => {
+        generate_language_functions!(@field_impl $($field)* { $($default)* $($entries)* });
     };
 
-    (@field_impl $enum_name:ident $field:ident { } ) => {
-        #[deprecated(note = "No language string provided for this field. Defaulting to 'ToDo!'")]
-        pub fn $field(&self) -> &'static str {
+    (@field_impl $vis:tt $placeholder_attr:tt $config:tt $enum_name:ident $key:ident [] $($field_tokens:tt)*) => {
+        generate_language_functions!(@field_impl $vis $placeholder_attr $config $enum_name $key [$key] $($field_tokens)*);
+    };
+
+    (@field_impl [$($vis:tt)*] [$($placeholder_attr:tt)*] $config:tt $enum_name:ident $key:ident [$field:ident] { } ) => {
+        $($placeholder_attr)*
+        $($vis)* fn $field(&self) -> &'static str {
+            generate_language_functions!(@placeholder $config $key);
+            generate_language_functions!(@hook $config self $key);
             "ToDo!"
         }
     };
 
-    (@field_impl $enum_name:ident $field:ident ( $($args:ident ),+ ) { } ) => {
-        #[deprecated(note = "No language string provided for this field. Defaulting to 'ToDo!'")]
-        pub fn $field<$( $args: std::fmt::Display, )*>(
+    (@field_impl [$($vis:tt)*] [$($placeholder_attr:tt)*] $config:tt $enum_name:ident $key:ident [$field:ident] ( $($args:ident ),+ ) { } ) => {
+        $($placeholder_attr)*
+        #[allow(unused_variables)]
+        $($vis)* fn $field<$( $args: $crate::AtlasFormat, )*>(
             &self,
             $( $args: $args, )*
         ) -> String {
+            generate_language_functions!(@placeholder $config $key);
+            generate_language_functions!(@hook $config self $key);
             String::from("ToDo!")
         }
     };
 
-    (@field_impl $enum_name:ident $field:ident ( $($args:ident $args_type:ty ),+ ) { } ) => {
-        #[deprecated(note = "No language string provided for this field. Defaulting to 'ToDo!'")]
-        pub fn $field(
+    (@field_impl [$($vis:tt)*] [$($placeholder_attr:tt)*] $config:tt $enum_name:ident $key:ident [$field:ident] ( $($args:ident $args_type:ty ),+ ) { } ) => {
+        $($placeholder_attr)*
+        #[allow(unused_variables)]
+        $($vis)* fn $field(
             &self,
             $( $args: $args_type, )+
         ) -> String {
+            generate_language_functions!(@placeholder $config $key);
+            generate_language_functions!(@hook $config self $key);
             String::from("ToDo!")
         }
     };
 
-    (@field_impl $enum_name:ident $field:ident {
+    (@field_impl [$($vis:tt)*] [$($placeholder_attr:tt)*] $config:tt $enum_name:ident $key:ident [$field:ident] {
         $first_lang:ident: $first_value:expr,
         $($lang:ident: $value:expr,)*
     }) => {
-        pub fn $field(&self) -> &'static str {
-            match self {
-                $( $enum_name::$lang => $value, )*
-                $enum_name::$first_lang | _ => $first_value,
-            }
+        $($vis)* fn $field(&self) -> &'static str {
+            generate_language_functions!(@hook $config self $key);
+            $crate::__language_atlas_languages! { @functions { $config [] self $enum_name $key $first_lang $first_value, } { $($lang: $value),* } }
         }
     };
 
-    (@field_impl $enum_name:ident $field:ident ( $($args:ident),+ ) {
+    (@field_impl [$($vis:tt)*] [$($placeholder_attr:tt)*] $config:tt $enum_name:ident $key:ident [$field:ident] ( $($args:ident),+ ) {
         $first_lang:ident: $first_value:expr,
         $($lang:ident: $value:expr,)*
     } ) => {
-        pub fn $field<$( $args: std::fmt::Display, )*>(
+        $($vis)* fn $field<$( $args: $crate::AtlasFormat, )*>(
             &self,
             $( $args: $args, )*
         ) -> String {
-            generate_language_functions! { @match_impl_string self $enum_name $first_lang $first_value, { $($lang: $value),* } }
+            generate_language_functions!(@hook $config self $key);
+            generate_language_functions!(@placeholders $key [$($args),*] { $first_lang: $first_value, $($lang: $value,)* });
+            $( let $args = $crate::__AtlasDisplay(&$args); )*
+            $crate::__language_atlas_languages!(@unused $($args),*);
+            $crate::__language_atlas_languages! { @functions { $config [format] self $enum_name $key $first_lang $first_value, } { $($lang: $value),* } }
         }
     };
 
-    (@field_impl $enum_name:ident $field:ident ( $($args:ident $args_type:ty ),+ ) {
+    (@field_impl [$($vis:tt)*] [$($placeholder_attr:tt)*] $config:tt $enum_name:ident $key:ident [$field:ident] ( $($args:ident $args_type:ty ),+ ) {
         $first_lang:ident: $first_value:expr,
         $($lang:ident: $value:expr,)*
     } ) => {
-        pub fn $field(
+        $($vis)* fn $field(
             &self,
             $( $args: $args_type, )+
         ) -> String {
-            generate_language_functions! { @match_impl_string self $enum_name $first_lang $first_value, { $($lang: $value),* } }
+            generate_language_functions!(@hook $config self $key);
+            generate_language_functions!(@placeholders $key [$($args),*] { $first_lang: $first_value, $($lang: $value,)* });
+            $crate::__language_atlas_languages!(@unused $($args),*);
+            $crate::__language_atlas_languages! { @functions { $config [format] self $enum_name $key $first_lang $first_value, } { $($lang: $value),* } }
+        }
+    };
+
+    (@field_impl [$($vis:tt)*] [$($placeholder_attr:tt)*] $config:tt $enum_name:ident $key:ident [$field:ident] select $( ( $selector:ident $(, $args:ident)* ) )? {
+        $($lang:ident $(. $cat:tt)?: $value:expr,)*
+    }) => {
+        compile_error!(concat!("Select field `", stringify!($key), "` needs a typed parameter."));
+    };
+
+    (@field_impl [$($vis:tt)*] [$($placeholder_attr:tt)*] $config:tt $enum_name:ident $key:ident [$field:ident] select ( $selector:ident $selector_type:ty $(, $args:ident $args_type:ty )* ) {
+        $($lang:ident $(. $cat:tt)?: $value:expr,)+
+    } ) => {
+        $($vis)* fn $field(
+            &self,
+            $selector: $selector_type,
+            $( $args: $args_type, )*
+        ) -> String {
+            generate_language_functions!(@hook $config self $key);
+            generate_language_functions!(@strict_other $config $key { $($lang $(. $cat)?: $value,)+ });
+            generate_language_functions!(@placeholders $key [$selector $(, $args)*] { $($lang $(. $cat)?: $value,)+ });
+            $crate::__language_atlas_languages!(@unused $selector $(, $args)*);
+            generate_language_functions! { @select_impl $config self $enum_name $key $selector [$selector_type] { $($lang $(. $cat)?: $value,)+ } }
+        }
+    };
+
+    (@field_impl [$($vis:tt)*] [$($placeholder_attr:tt)*] $config:tt $enum_name:ident $key:ident [$field:ident] $($kind:ident)? {
+        $($lang:ident $(. $cat:tt)?: $value:expr,)*
+    }) => {
+        compile_error!(concat!("Plural field `", stringify!($key), "` needs a count parameter."));
+    };
+
+    (@field_impl [] [] $config:tt $enum_name:ident $key:ident [$field:ident] $($kind:ident)? ( $count:ident $(, $args:ident)* ) { $($entries:tt)* } ) => {
+        compile_error!(concat!("Plural field `", stringify!($key), "` needs a typed count to implement a schema, e.g. `", stringify!($key), "(count: u32)`."));
+    };
+    (@field_impl [$($vis:tt)*] [$($placeholder_attr:tt)*] $config:tt $enum_name:ident $key:ident [$field:ident] $($kind:ident)? ( $count:ident $(, $args:ident)* ) {
+        $($lang:ident $(. $cat:tt)?: $value:expr,)+
+    } ) => {
+        $($vis)* fn $field<$count: std::fmt::Display + $crate::plural::PluralOperand, $( $args: $crate::AtlasFormat, )*>(
+            &self,
+            $count: $count,
+            $( $args: $args, )*
+        ) -> String {
+            generate_language_functions!(@hook $config self $key);
+            generate_language_functions!(@strict_other $config $key { $($lang $(. $cat)?: $value,)+ });
+            generate_language_functions!(@placeholders $key [$count $(, $args)*] { $($lang $(. $cat)?: $value,)+ });
+            $( let $args = $crate::__AtlasDisplay(&$args); )*
+            $crate::__language_atlas_languages!(@unused $count $(, $args)*);
+            generate_language_functions! { @plural_impl $config [$($kind)?] self $enum_name $key $count { $($lang $(. $cat)?: $value,)+ } }
+        }
+    };
+
+    (@field_impl [$($vis:tt)*] [$($placeholder_attr:tt)*] $config:tt $enum_name:ident $key:ident [$field:ident] $($kind:ident)? ( $count:ident $count_type:ty $(, $args:ident $args_type:ty )* ) {
+        $($lang:ident $(. $cat:tt)?: $value:expr,)+
+    } ) => {
+        $($vis)* fn $field(
+            &self,
+            $count: $count_type,
+            $( $args: $args_type, )*
+        ) -> String {
+            generate_language_functions!(@hook $config self $key);
+            generate_language_functions!(@strict_other $config $key { $($lang $(. $cat)?: $value,)+ });
+            generate_language_functions!(@placeholders $key [$count $(, $args)*] { $($lang $(. $cat)?: $value,)+ });
+            $crate::__language_atlas_languages!(@unused $count $(, $args)*);
+            generate_language_functions! { @plural_impl $config [$($kind)?] self $enum_name $key $count { $($lang $(. $cat)?: $value,)+ } }
+        }
+    };
+
+    (@plural_impl $config:tt $kind:tt $self:ident $enum_name:ident $field:ident $count:ident { $($entries:tt)* }) => {
+        $crate::__language_atlas_languages! { @plural ($) { @plural_render $config $kind $self $enum_name $field $count } $config { $($entries)* } }
+    };
+
+    (@plural_render $config:tt $kind:tt $self:ident $enum_name:ident $field:ident $count:ident {
+        $first_lang:ident $(. $first_cat:tt)?: $first_value:expr,
+        $($lang:ident $(. $cat:tt)?: $value:expr,)*
+    }) => {{
+        $crate::__language_atlas_plural!(@kind $kind);
+        let n = $crate::plural::PluralOperand::plural_operand(&$count);
+        #[allow(unused_variables)]
+        let provides = |lang: &$enum_name| generate_language_functions!(@plural_match [provides] $kind $enum_name lang n {
+            $first_lang $(. $first_cat)?: $first_value, $($lang $(. $cat)?: $value,)*
+        }).is_some();
+        let resolve = |lang: &$enum_name| -> Option<String> {
+            generate_language_functions!(@plural_match [format] $kind $enum_name lang n { $first_lang $(. $first_cat)?: $first_value, $($lang $(. $cat)?: $value,)* })
+        };
+        let render = |lang: &$enum_name| -> String {
+            let default = generate_language_functions!(@default_language $config $enum_name $first_lang { $($lang),* });
+            resolve(lang).or_else(|| resolve(&default)).unwrap_or_else(|| format!($first_value))
+        };
+        $crate::__language_atlas_languages! { @functions { $config [plural provides render] $self $enum_name $field $first_lang $enum_name::$first_lang, } { $($lang: $enum_name::$lang),* } }
+    }};
+
+    // Selects the string of `lang` for the count `n`: an exact number, then the plural category, then `other`.
+    (@plural_match $action:tt $kind:tt $enum_name:ident $lang_var:ident $n:ident { $($lang:ident $(. $cat:tt)?: $value:expr,)* }) => {
+        match $lang_var {
+            $( $enum_name::$lang if $crate::__language_atlas_plural!(@number $n $($cat)?) => generate_language_functions!(@plural_action $action $value), )*
+            $( $enum_name::$lang if $crate::__language_atlas_plural!(@category $kind $lang_var $n $($cat)?) => generate_language_functions!(@plural_action $action $value), )*
+            $( $enum_name::$lang if $crate::__language_atlas_plural!(@other $($cat)?) => generate_language_functions!(@plural_action $action $value), )*
+            _ => None,
+        }
+    };
+
+    (@plural_action [provides] $value:expr) => { Some(()) };
+    (@plural_action [format] $value:expr) => { Some(format!($value)) };
+
+    (@select_impl $config:tt $self:ident $enum_name:ident $field:ident $selector:ident $selector_type:tt { $($entries:tt)* }) => {
+        $crate::__language_atlas_languages! { @plural ($) { @select_render $config $self $enum_name $field $selector $selector_type } $config { $($entries)* } }
+    };
+
+    (@select_render $config:tt $self:ident $enum_name:ident $field:ident $selector:ident $selector_type:tt {
+        $first_lang:ident $(. $first_cat:tt)?: $first_value:expr,
+        $($lang:ident $(. $cat:tt)?: $value:expr,)*
+    }) => {{
+        #[allow(unused_variables)]
+        let provides = |lang: &$enum_name| generate_language_functions!(@select_match [provides] $enum_name lang $selector $selector_type {
+            $first_lang $(. $first_cat)?: $first_value, $($lang $(. $cat)?: $value,)*
+        }).is_some();
+        let resolve = |lang: &$enum_name| -> Option<String> {
+            generate_language_functions!(@select_match [format] $enum_name lang $selector $selector_type { $first_lang $(. $first_cat)?: $first_value, $($lang $(. $cat)?: $value,)* })
+        };
+        let render = |lang: &$enum_name| -> String {
+            let default = generate_language_functions!(@default_language $config $enum_name $first_lang { $($lang),* });
+            resolve(lang).or_else(|| resolve(&default)).unwrap_or_else(|| format!($first_value))
+        };
+        $crate::__language_atlas_languages! { @functions { $config [plural provides render] $self $enum_name $field $first_lang $enum_name::$first_lang, } { $($lang: $enum_name::$lang),* } }
+    }};
+
+    // Selects the string of `lang` for the variant of `selector`, then `other`.
+    (@select_match $action:tt $enum_name:ident $lang_var:ident $selector:ident $selector_type:tt { $($lang:ident $(. $cat:tt)?: $value:expr,)* }) => {
+        match $lang_var {
+            $( $enum_name::$lang if $crate::__language_atlas_plural!(@select $selector $selector_type $($cat)?) => generate_language_functions!(@plural_action $action $value), )*
+            $( $enum_name::$lang if $crate::__language_atlas_plural!(@other $($cat)?) => generate_language_functions!(@plural_action $action $value), )*
+            _ => None,
+        }
+    };
+
+    (@match_impl [missing: [$(Default)?] hook: $hook:tt languages: $languages:tt coverage: $coverage:tt default: $default:tt fallback: $fallback:tt] $format:tt $self:ident $enum_name:ident $field:ident $first_lang:ident $first_value:expr, { $($lang:ident: $value:expr),* }) => {
+        match $self {
+            $( $enum_name::$lang if generate_language_functions!(@provided $format $value) => generate_language_functions!(@value $format $value), )*
+            $enum_name::$first_lang if generate_language_functions!(@provided $format $first_value) => generate_language_functions!(@value $format $first_value),
+            _ => generate_language_functions!(@chain $fallback {
+                generate_language_functions!(@fallback $default $format $enum_name $first_lang $first_value, { $($lang: $value),* })
+            } $self $format $enum_name $first_lang $first_value, { $($lang: $value),* }),
         }
     };
 
-    (@match_impl_string $self:ident $enum_name:ident $first_lang:ident $first_value:expr, { $($lang:ident: $value:expr),* }) => {
+    (@match_impl [missing: [Strict] $($config:tt)*] $format:tt $self:ident $enum_name:ident $field:ident $first_lang:ident $first_value:expr, { $($lang:ident: $value:expr),* }) => {
         match $self {
-            $( $enum_name::$lang => format!($value), )*
-            $enum_name::$first_lang | _ => format!($first_value),
+            $( $enum_name::$lang => generate_language_functions!(@value $format $value), )*
+            $enum_name::$first_lang => generate_language_functions!(@value $format $first_value),
         }
     };
 
+    (@match_impl [missing: [$missing:ident] hook: $hook:tt languages: $languages:tt coverage: $coverage:tt default: $default:tt fallback: $fallback:tt] $format:tt $self:ident $enum_name:ident $field:ident $first_lang:ident $first_value:expr, { $($lang:ident: $value:expr),* }) => {
+        match $self {
+            $( $enum_name::$lang if generate_language_functions!(@provided $format $value) => generate_language_functions!(@value $format $value), )*
+            $enum_name::$first_lang if generate_language_functions!(@provided $format $first_value) => generate_language_functions!(@value $format $first_value),
+            _ => generate_language_functions!(@chain $fallback {
+                generate_language_functions!(@missing $missing $format $field { })
+            } $self $format $enum_name $first_lang $first_value, { $($lang: $value),* }),
+        }
+    };
+
+    (@match_impl [missing: [{ $($env:ident: $missing:ident),+ $(,)? }] hook: $hook:tt languages: $languages:tt coverage: $coverage:tt default: $default:tt fallback: $fallback:tt] $format:tt $self:ident $enum_name:ident $field:ident $first_lang:ident $first_value:expr, { $($lang:ident: $value:expr),* }) => {
+        match $self {
+            $( $enum_name::$lang if generate_language_functions!(@provided $format $value) => generate_language_functions!(@value $format $value), )*
+            $enum_name::$first_lang if generate_language_functions!(@provided $format $first_value) => generate_language_functions!(@value $format $first_value),
+            _ => generate_language_functions!(@chain $fallback {
+                let fallback = || generate_language_functions!(@fallback $default $format $enum_name $first_lang $first_value, { $($lang: $value),* });
+                match option_env!("LANGUAGE_ATLAS_ENV") {
+                    $( Some(stringify!($env)) => generate_language_functions!(@missing $missing $format $field { fallback() }), )+
+                    _ => fallback(),
+                }
+            } $self $format $enum_name $first_lang $first_value, { $($lang: $value),* }),
+        }
+    };
+
+    (@chain [] $otherwise:block $($match_tokens:tt)*) => { $otherwise };
+    (@chain [$($chains:tt)+] $otherwise:block $self:ident $format:tt $enum_name:ident $first_lang:ident $first_value:expr, { $($lang:ident: $value:expr),* }) => {{
+        let (parent, mut steps) = generate_language_functions!(@parents $enum_name [] $($chains)+);
+        let mut language = parent($self);
+        loop {
+            match language {
+                Some(next) if steps > 0 => {
+                    steps -= 1;
+                    match next {
+                        $( $enum_name::$lang if generate_language_functions!(@provided $format $value) => break generate_language_functions!(@value $format $value), )*
+                        $enum_name::$first_lang if generate_language_functions!(@provided $format $first_value) => break generate_language_functions!(@value $format $first_value),
+                        _ => language = parent(next),
+                    }
+                }
+                _ => break $otherwise,
+            }
+        }
+    }};
+
+    (@parents $enum_name:ident [$($links:tt)*] $lang:ident -> $parent:ident $(-> $rest:ident)* $(, $($chains:tt)*)?) => {
+        generate_language_functions!(@parents $enum_name [$($links)* ($lang $parent)] $parent $(-> $rest)* $(, $($chains)*)?)
+    };
+    (@parents $enum_name:ident $links:tt $lang:ident $(, $($chains:tt)*)?) => {
+        generate_language_functions!(@parents $enum_name $links $($($chains)*)?)
+    };
+    (@parents $enum_name:ident [$(($lang:ident $parent:ident))*]) => {
+        (
+            |lang: &$enum_name| -> Option<&'static $enum_name> {
+                match lang {
+                    $( $enum_name::$lang => Some(&$enum_name::$parent), )*
+                    _ => None,
+                }
+            },
+            [$(stringify!($lang)),*].len(),
+        )
+    };
+
+    (@default_language [missing: $missing:tt hook: $hook:tt languages: $languages:tt coverage: $coverage:tt default: $default:tt fallback: $fallback:tt] $enum_name:ident $first_lang:ident { $($lang:ident),* }) => {
+        generate_language_functions!(@fallback $default [] $enum_name $first_lang $enum_name::$first_lang, { $($lang: $enum_name::$lang),* })
+    };
+
+    (@fallback [@marked] $format:tt $enum_name:ident $first_lang:ident $first_value:expr, { $($lang:ident: $value:expr),* }) => {
+        generate_language_functions!(@value $format $first_value)
+    };
+
+    (@fallback [$default:ident] $format:tt $enum_name:ident $first_lang:ident $first_value:expr, { $($lang:ident: $value:expr),* }) => {
+        match option_env!("LANGUAGE_ATLAS_DEFAULT") {
+            $( Some(stringify!($lang)) => generate_language_functions!(@value $format $value), )*
+            Some(stringify!($first_lang)) => generate_language_functions!(@value $format $first_value),
+            _ => match $enum_name::$default {
+                $( $enum_name::$lang => generate_language_functions!(@value $format $value), )*
+                _ => generate_language_functions!(@value $format $first_value),
+            },
+        }
+    };
+
+    (@fallback [] $format:tt $enum_name:ident $first_lang:ident $first_value:expr, { $($lang:ident: $value:expr),* }) => {
+        match option_env!("LANGUAGE_ATLAS_DEFAULT") {
+            $( Some(stringify!($lang)) => generate_language_functions!(@value $format $value), )*
+            _ => generate_language_functions!(@value $format $first_value),
+        }
+    };
+
+    // Every placeholder of a parameter function has to name a parameter of the key.
+    (@placeholders $key:ident $parameters:tt { $($lang:ident $(. $cat:tt)?: $value:expr,)* }) => {
+        $( generate_language_functions!(@placeholders_of $key $parameters [$lang $(. $cat)?] $value); )*
+    };
+    (@placeholders_of $key:ident [$($parameter:ident),*] [$($entry:tt)*] $value:expr) => {
+        const _: () = assert!(
+            $crate::__uses_parameters($value, &[$(stringify!($parameter)),*]),
+            concat!("The `", stringify!($($entry)*), "` string of key `", stringify!($key), "` has a placeholder that is not a parameter of the key.")
+        );
+    };
+
+    (@placeholder [missing: [Strict] $($config:tt)*] $key:ident) => {
+        compile_error!(concat!("Key `", stringify!($key), "` has no language strings. `Missing: Strict` requires a string for every language."));
+    };
+    (@placeholder $config:tt $key:ident) => { };
+
+    // `Missing: Strict` requires a string without category or an `other` string for every language of plural and select fields.
+    (@strict_other [missing: [Strict] $($config:tt)*] $key:ident { $($entries:tt)* }) => {
+        generate_language_functions!(@strict_other_collect ($) $key [] [] $($entries)*);
+    };
+    (@strict_other $config:tt $key:ident $entries:tt) => { };
+
+    (@strict_other_collect $d:tt $key:ident [$($others:ident)*] [$($all:ident)*] $lang:ident: $value:expr, $($rest:tt)*) => {
+        generate_language_functions!(@strict_other_collect $d $key [$($others)* $lang] [$($all)* $lang] $($rest)*);
+    };
+    (@strict_other_collect $d:tt $key:ident [$($others:ident)*] [$($all:ident)*] $lang:ident . other: $value:expr, $($rest:tt)*) => {
+        generate_language_functions!(@strict_other_collect $d $key [$($others)* $lang] [$($all)* $lang] $($rest)*);
+    };
+    (@strict_other_collect $d:tt $key:ident $others:tt [$($all:ident)*] $lang:ident . $cat:tt: $value:expr, $($rest:tt)*) => {
+        generate_language_functions!(@strict_other_collect $d $key $others [$($all)* $lang] $($rest)*);
+    };
+    (@strict_other_collect ($d:tt) $key:ident [$($others:ident)*] [$($all:ident)*]) => {
+        const _: () = {
+            macro_rules! __language_atlas_strict {
+                $( ($others $d($d rest:ident)*) => { __language_atlas_strict! { $d($d rest)* } }; )*
+                ($d lang:ident $d($d rest:ident)*) => {
+                    compile_error!(concat!(
+                        "Key `", stringify!($key), "` has no `other` string for `", stringify!($d lang), "`. ",
+                        "`Missing: Strict` requires an `other` string or a string without category for every language of plural and select fields."
+                    ));
+                };
+                () => { };
+            }
+            __language_atlas_strict! { $($all)* }
+        };
+    };
+
+    (@hook [missing: $missing:tt hook: [] $($config:tt)*] $self:ident $field:ident) => { };
+    (@hook [missing: $missing:tt hook: [$($hook:ident)::+] $($config:tt)*] $self:ident $field:ident) => {
+        $($hook)::+($crate::__key_name(stringify!($field)), $self)
+    };
+
+    (@value [] $value:expr) => { $value };
+    (@value [format] $value:expr) => { format!($value) };
+    (@value [plural $provides:ident $render:ident] $value:expr) => { $render(&$value) };
+
+    (@provided [plural $provides:ident $render:ident] $value:expr) => { $provides(&$value) };
+    (@provided $format:tt $value:expr) => { true };
+
+    (@missing Default $format:tt $field:ident { $($fallback:tt)* }) => { $($fallback)* };
+    (@missing KeyName [] $field:ident $fallback:tt) => { generate_language_functions!(@wrapped_key "[" $field "]") };
+    (@missing KeyName [$($format:tt)+] $field:ident $fallback:tt) => { String::from(generate_language_functions!(@wrapped_key "[" $field "]")) };
+    (@missing Marker [] $field:ident $fallback:tt) => { generate_language_functions!(@wrapped_key "⟦" $field "⟧") };
+    (@missing Marker [$($format:tt)+] $field:ident $fallback:tt) => { String::from(generate_language_functions!(@wrapped_key "⟦" $field "⟧")) };
+    (@missing Empty [] $field:ident $fallback:tt) => { "" };
+    (@missing Empty [$($format:tt)+] $field:ident $fallback:tt) => { String::new() };
+    (@missing Strict $format:tt $field:ident $fallback:tt) => {
+        compile_error!("`Strict` can not be selected with `LANGUAGE_ATLAS_ENV`.")
+    };
+    (@missing $missing:ident $format:tt $field:ident $fallback:tt) => {
+        compile_error!(concat!("Unknown missing string policy `", stringify!($missing), "`. Expected `Default`, `KeyName`, `Marker`, `Empty` or `Strict`."))
+    };
+
+    (@wrapped_key $open:literal $field:ident $close:literal) => {{
+        const KEY: &str = stringify!($field);
+        const BYTES: [u8; $open.len() + $crate::__key_name(KEY).len() + $close.len()] = $crate::__wrapped_key($open, KEY, $close);
+        const NAME: &str = match ::core::str::from_utf8(&BYTES) {
+            Ok(name) => name,
+            Err(_) => panic!("key names are valid UTF-8"),
+        };
+        NAME
+    }};
+}
+
+
+/// This macro declares a trait that describes the keys and parameter signatures of a language atlas
+/// without providing any language strings. It allows a "schema" crate to define the keys once, while
+/// downstream crates supply the translations with `generate_language_functions!` and the `Schema` header.
+///
+/// # Notes
+/// - The signatures follow the same rules as `generate_language_functions!`.
+/// - Keys without parameters return a `&'static str`, keys with parameters return a `String`.
+/// - A downstream atlas that misses a key or uses a different signature fails to compile.
+/// - Placeholder fields (`dummy { }`) in a schema implementation are not marked as deprecated.
+/// - Plural, `ordinal` and `select` fields are declared without their mode and with typed parameters, e.g. `files(count: u32)` or `invited(gender: Gender, name: &str)`. Writing the mode in the schema fails to compile.
+///
+/// # Example
+#[cfg_attr(not(feature = "default-lang-only"), doc = "```rust")]
+#[cfg_attr(feature = "default-lang-only", doc = "```rust,ignore")]
+/// mod schema {
+///     use language_atlas::generate_language_schema;
+///
+///     generate_language_schema! {
+///         pub trait CoreStrings {
+///             greeting
+///             farewell(name)
+///             date(day: u8, month: u8, year: u16)
+///         }
+///     }
+/// }
+///
+/// use language_atlas::generate_language_functions;
+/// use schema::CoreStrings;
+///
+/// enum Language {
+///     English,
+///     French,
+/// }
+///
+/// generate_language_functions! {
+///     LanguageEnum: Language, Schema: schema::CoreStrings
+///     greeting {
+///         English: "Hello"
+///         French:  "Bonjour"
+///     }
+///     farewell(name) {
+///         English: "Goodbye, {name}"
+///         French:  "Au revoir, {name}"
+///     }
+///     date(day: u8, month: u8, year: u16) {
+///         French:  "{day}/{month}/{year}"
+///         English: "{month}/{day}/{year}"
+///     }
+/// }
+///
+/// fn main() {
+///     let lang = Language::French;
+///     assert_eq!(lang.greeting(), "Bonjour");
+///     assert_eq!(lang.farewell("Jean"), "Au revoir, Jean");
+///     assert_eq!(lang.date(1, 2, 2021), "1/2/2021");
+/// }
+/// ```
+#[macro_export]
+macro_rules! generate_language_schema {
+    (
+        $(#[$meta:meta])*
+        $vis:vis trait $trait_name:ident {
+            $($field:ident $(@fn($method:ident))? $( ( $($args:ident $(: $args_type:ty )? ),+ ) )?)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[allow(non_camel_case_types)]
+        $vis trait $trait_name {
+            $(
+                generate_language_schema!(@field_sig $field [$($method)?] $( ( $($args $($args_type)? ),* ) )? );
+            )*
+        }
+    };
+
+    (@field_sig select $method:tt $($args:tt)?) => {
+        compile_error!("Plural, `ordinal` and `select` fields are declared in a schema without their mode and with typed parameters, e.g. `files(count: u32)`.");
+    };
+    (@field_sig ordinal $method:tt $($args:tt)?) => {
+        compile_error!("Plural, `ordinal` and `select` fields are declared in a schema without their mode and with typed parameters, e.g. `files(count: u32)`.");
+    };
+    (@field_sig plural $method:tt $($args:tt)?) => {
+        compile_error!("Plural, `ordinal` and `select` fields are declared in a schema without their mode and with typed parameters, e.g. `files(count: u32)`.");
+    };
+
+    (@field_sig $key:ident [] $($args:tt)?) => {
+        generate_language_schema!(@field_sig $key [$key] $($args)?);
+    };
+
+    (@field_sig $key:ident [$field:ident]) => {
+        fn $field(&self) -> &'static str;
+    };
+
+    (@field_sig $key:ident [$field:ident] ( $($args:ident),+ )) => {
+        fn $field<$( $args: $crate::AtlasFormat, )*>(
+            &self,
+            $( $args: $args, )*
+        ) -> String;
+    };
+
+    (@field_sig $key:ident [$field:ident] ( $($args:ident $args_type:ty),+ )) => {
+        fn $field(
+            &self,
+            $( $args: $args_type, )+
+        ) -> String;
+    };
+}
+
+
+/// This macro generates a `localize` function for an ordinary enum (order status, weekday, error codes, ...)
+/// that returns the translation of the variant in the given language.
+///
+/// # Notes
+/// - The first language variant of each enum variant is considered the default value.
+/// - If a language variant is not provided for an enum variant, the default value is used.
+/// - Variants with fields are supported, the fields are ignored.
+/// - Every variant of the enum has to be listed.
+///
+/// # Example
+#[cfg_attr(not(feature = "default-lang-only"), doc = "```rust")]
+#[cfg_attr(feature = "default-lang-only", doc = "```rust,ignore")]
+/// use language_atlas::generate_localized_enum;
+///
+/// enum Language {
+///     English,
+///     German,
+/// }
+///
+/// enum OrderStatus {
+///     Pending,
+///     Shipped { tracking_id: u32 },
+/// }
+///
+/// generate_localized_enum! {
+///     LanguageEnum: Language
+///     OrderStatus {
+///         Pending {
+///             English: "Pending"
+///             German:  "Ausstehend"
+///         }
+///         Shipped {
+///             English: "Shipped"
+///             German:  "Versandt"
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(OrderStatus::Pending.localize(&Language::German), "Ausstehend");
+///     assert_eq!(OrderStatus::Shipped { tracking_id: 42 }.localize(&Language::English), "Shipped");
+/// }
+/// ```
+#[macro_export]
+macro_rules! generate_localized_enum {
+    (
+        LanguageEnum: $enum_name:ident
+        $target:ident {
+            $($variant:ident {
+                $($lang:ident: $value:expr $(,)? )+
+            })*
+        }
+    ) => {
+        #[allow(unreachable_patterns)]
+        impl $target {
+            pub fn localize(&self, lang: &$enum_name) -> &'static str {
+                match self {
+                    $(
+                        $target::$variant { .. } => $crate::__language_atlas_languages!(@localized_enum { lang $enum_name } { $($lang: $value,)+ }),
+                    )*
+                }
+            }
+        }
+    };
+
+    (@match_impl $lang_var:ident $enum_name:ident {
+        $first_lang:ident: $first_value:expr,
+        $($lang:ident: $value:expr,)*
+    }) => {
+        match $lang_var {
+            $( $enum_name::$lang => $value, )*
+            $enum_name::$first_lang => $first_value,
+            _ => match option_env!("LANGUAGE_ATLAS_DEFAULT") {
+                $( Some(stringify!($lang)) => $value, )*
+                _ => $first_value,
+            },
+        }
+    };
+}
+
+
+/// Declares the keys of yes/no, on/off and enabled/disabled strings.
+/// The crate ships no strings for these keys, they are provided by implementing the trait for a language enum
+/// with the `Schema` header of `generate_language_functions!`. As a schema implements all its keys, this is
+/// an own invocation next to the other keys of the enum.
+/// The provided functions select the matching string for a `bool`.
+///
+/// # Example
+#[cfg_attr(not(feature = "default-lang-only"), doc = "```rust")]
+#[cfg_attr(feature = "default-lang-only", doc = "```rust,ignore")]
+/// use language_atlas::{generate_language_functions, BooleanStrings};
+///
+/// enum Language {
+///     English,
+///     German,
+/// }
+///
+/// generate_language_functions! {
+///     LanguageEnum: Language, Schema: language_atlas::BooleanStrings
+///     yes      { English: "Yes"      German: "Ja" }
+///     no       { English: "No"       German: "Nein" }
+///     on       { English: "On"       German: "An" }
+///     off      { English: "Off"      German: "Aus" }
+///     enabled  { English: "Enabled"  German: "Aktiviert" }
+///     disabled { English: "Disabled" German: "Deaktiviert" }
+/// }
+///
+/// fn main() {
+///     let lang = Language::German;
+///     assert_eq!(lang.yes_no(true), "Ja");
+///     assert_eq!(lang.on_off(false), "Aus");
+///     assert_eq!(lang.enabled_disabled(true), "Aktiviert");
+/// }
+/// ```
+pub trait BooleanStrings {
+    fn yes(&self) -> &'static str;
+    fn no(&self) -> &'static str;
+    fn on(&self) -> &'static str;
+    fn off(&self) -> &'static str;
+    fn enabled(&self) -> &'static str;
+    fn disabled(&self) -> &'static str;
+
+    fn yes_no(&self, value: bool) -> &'static str {
+        if value { self.yes() } else { self.no() }
+    }
+
+    fn on_off(&self, value: bool) -> &'static str {
+        if value { self.on() } else { self.off() }
+    }
+
+    fn enabled_disabled(&self, value: bool) -> &'static str {
+        if value { self.enabled() } else { self.disabled() }
+    }
+}
+
+/// A parameter that is only computed if the string of the selected language uses its placeholder.
+/// Can be passed to parameters without a type, e.g. `lang.summary(Lazy(|| expensive_summary()))`.
+/// The value is computed at most once, even if the placeholder is used more than once.
+///
+/// # Example
+#[cfg_attr(not(feature = "default-lang-only"), doc = "```rust")]
+#[cfg_attr(feature = "default-lang-only", doc = "```rust,ignore")]
+/// use language_atlas::{generate_language_functions, Lazy};
+///
+/// enum Language {
+///     English,
+///     German,
+/// }
+///
+/// generate_language_functions! {
+///     LanguageEnum: Language
+///     saved(details) {
+///         English: "Saved ({details})"
+///         German:  "Gespeichert"
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(Language::English.saved(Lazy(|| 2 + 3)), "Saved (5)");
+///     assert_eq!(Language::German.saved(Lazy(|| -> u32 { unreachable!() })), "Gespeichert");
+/// }
+/// ```
+pub struct Lazy<F, T> {
+    compute: F,
+    value: std::cell::OnceCell<T>,
+}
+
+/// Creates a [`Lazy`](struct@Lazy) parameter that computes its value with `compute` when it is first displayed.
+#[allow(non_snake_case)]
+pub fn Lazy<F: Fn() -> T, T>(compute: F) -> Lazy<F, T> {
+    Lazy { compute, value: std::cell::OnceCell::new() }
+}
+
+impl<F: Fn() -> T, T: std::fmt::Display> std::fmt::Display for Lazy<F, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.get_or_init(|| (self.compute)()).fmt(f)
+    }
+}
+
+/// Formats untyped parameters of the generated functions.
+///
+/// It is implemented for every type that implements `Display`. Implementing it for a type without a `Display`
+/// implementation renders that type the same way in every string, e.g. a masked user id.
+///
+/// A type that implements `Display` always uses its `Display` implementation, it can not get a different
+/// format for the strings of the atlas. To render such a type differently, e.g. a user id whose `Display`
+/// implementation is used for logs, pass a newtype that implements `AtlasFormat` but not `Display`.
+/// Typed parameters use the traits of their type and don't use `AtlasFormat`.
+///
+#[cfg_attr(not(feature = "default-lang-only"), doc = "```rust")]
+#[cfg_attr(feature = "default-lang-only", doc = "```rust,ignore")]
+/// use language_atlas::{generate_language_functions, AtlasFormat};
+///
+/// struct UserId(u64);
+///
+/// impl AtlasFormat for UserId {
+///     fn atlas_fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "#{}…", self.0 % 1000)
+///     }
+/// }
+///
+/// enum Language {
+///     English,
+///     German,
+/// }
+///
+/// generate_language_functions! {
+///     LanguageEnum: Language
+///     blocked(user) {
+///         English: "User {user} is blocked"
+///         German:  "Nutzer {user} ist gesperrt"
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(Language::English.blocked(UserId(123456)), "User #456… is blocked");
+///     assert_eq!(Language::German.blocked("Ada"), "Nutzer Ada ist gesperrt");
+/// }
+/// ```
+pub trait AtlasFormat {
+    fn atlas_fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
+}
+
+impl<T: std::fmt::Display + ?Sized> AtlasFormat for T {
+    fn atlas_fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// Displays an untyped parameter with its [`AtlasFormat`] implementation.
+#[doc(hidden)]
+pub struct __AtlasDisplay<'a, T: ?Sized>(pub &'a T);
+
+impl<T: AtlasFormat + ?Sized> std::fmt::Display for __AtlasDisplay<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.atlas_fmt(f)
+    }
+}
+
+/// Checks that a language of the `Coverage` header provides at least `percent` percent of `keys` keys.
+#[doc(hidden)]
+pub fn __check_coverage(language: &str, keys: usize, missing: &[&str], percent: usize) -> Result<(), String> {
+    let coverage = ((keys - missing.len()) * 100).checked_div(keys).unwrap_or(100);
+    if coverage >= percent {
+        Ok(())
+    } else {
+        Err(format!("{} covers {}% of the keys, expected at least {}%. Missing: {:?}", language, coverage, percent, missing))
+    }
+}
+
+/// Returns the row of a key in the table of the `Reference` header. The default strings are the strings of the
+/// language of the entry marked with `default`, then of the `LANGUAGE_ATLAS_DEFAULT` language, of the `Default`
+/// language and of the first entry. Plural and select strings are listed with their category.
+#[doc(hidden)]
+pub fn __reference_row(key: &str, parameters: &[&str], entries: &[(&str, bool, &str, &str)], default: Option<&str>, environment: Option<&str>) -> String {
+    let environment = if cfg!(feature = "default-lang-only") { None } else { environment };
+    let provided = |language: Option<&str>| entries.iter().map(|(lang, _, _, _)| *lang).find(|lang| Some(*lang) == language);
+    let language = entries.iter().find(|(_, marked, _, _)| *marked).map(|(lang, _, _, _)| *lang)
+        .or_else(|| provided(environment))
+        .or_else(|| provided(default))
+        .or(entries.first().map(|(lang, _, _, _)| *lang));
+    let strings: Vec<String> = entries.iter()
+        .filter(|(lang, _, _, _)| Some(*lang) == language)
+        .map(|(_, _, category, value)| if category.is_empty() { value.to_string() } else { format!("`{}`: {}", category, value) })
+        .collect();
+    let cell = |text: &str| text.replace('|', "\\|").replace('\n', "<br>");
+    let parameters = if parameters.is_empty() { String::new() } else { format!("`{}`", parameters.join(", ")) };
+    format!("| `{}` | {} | {} |\n", key, cell(&parameters), cell(&strings.join("\n")))
+}
+
+/// Returns whether `name` is one of `languages`. An unset or empty name and the `default-lang-only` feature,
+/// which ignores `LANGUAGE_ATLAS_DEFAULT`, always pass.
+#[doc(hidden)]
+pub const fn __is_language(name: Option<&str>, languages: &[&str]) -> bool {
+    let name = match name {
+        Some(name) if !name.is_empty() && !cfg!(feature = "default-lang-only") => name.as_bytes(),
+        _ => return true,
+    };
+    let mut index = 0;
+    while index < languages.len() {
+        let language = languages[index].as_bytes();
+        if language.len() == name.len() {
+            let mut i = 0;
+            while i < name.len() && language[i] == name[i] {
+                i += 1;
+            }
+            if i == name.len() {
+                return true;
+            }
+        }
+        index += 1;
+    }
+    false
+}
+
+/// Returns whether every placeholder of the `format!` string `value` names one of `parameters`. Named widths
+/// and precisions like `{name:>width$}` count as placeholders, positional placeholders are left to `format!`.
+#[doc(hidden)]
+pub const fn __uses_parameters(value: &str, parameters: &[&str]) -> bool {
+    let bytes = value.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'{' && i + 1 < bytes.len() && bytes[i + 1] == b'{' {
+            i += 2;
+            continue;
+        }
+        if bytes[i] != b'{' {
+            i += 1;
+            continue;
+        }
+        i += 1;
+        let start = i;
+        while i < bytes.len() && bytes[i] != b'}' && bytes[i] != b':' {
+            i += 1;
+        }
+        if !is_parameter(bytes, start, i, parameters) {
+            return false;
+        }
+        while i < bytes.len() && bytes[i] != b'}' {
+            if bytes[i] == b'$' {
+                let mut name = i;
+                while name > start && is_ident_byte(bytes[name - 1]) {
+                    name -= 1;
+                }
+                if !is_parameter(bytes, name, i, parameters) {
+                    return false;
+                }
+            }
+            i += 1;
+        }
+    }
+    true
+}
+
+/// Returns whether `bytes[start..end]` is a positional placeholder or names one of `parameters`.
+const fn is_parameter(bytes: &[u8], start: usize, end: usize, parameters: &[&str]) -> bool {
+    let mut start = start;
+    let mut end = end;
+    while start < end && bytes[start] == b' ' {
+        start += 1;
+    }
+    while end > start && bytes[end - 1] == b' ' {
+        end -= 1;
+    }
+    if start == end || bytes[start].is_ascii_digit() {
+        return true;
+    }
+    let mut index = 0;
+    while index < parameters.len() {
+        let parameter = __key_name(parameters[index]).as_bytes();
+        if parameter.len() == end - start {
+            let mut i = 0;
+            while i < parameter.len() && parameter[i] == bytes[start + i] {
+                i += 1;
+            }
+            if i == parameter.len() {
+                return true;
+            }
+        }
+        index += 1;
+    }
+    false
+}
+
+const fn is_ident_byte(byte: u8) -> bool {
+    byte == b'_' || byte.is_ascii_alphanumeric() || byte >= 0x80
+}
+
+/// Returns the name of a key without the `r#` prefix of a raw identifier.
+#[doc(hidden)]
+pub const fn __key_name(key: &str) -> &str {
+    match key.as_bytes() {
+        [b'r', b'#', ..] => key.split_at(2).1,
+        _ => key,
+    }
+}
+
+/// Returns the bytes of the key name between `open` and `close` for the `KeyName` and `Marker` policies.
+/// `N` is the length of the result.
+#[doc(hidden)]
+pub const fn __wrapped_key<const N: usize>(open: &str, key: &str, close: &str) -> [u8; N] {
+    let mut bytes = [0; N];
+    let mut len = 0;
+    let parts = [open.as_bytes(), __key_name(key).as_bytes(), close.as_bytes()];
+    let mut part = 0;
+    while part < parts.len() {
+        let mut i = 0;
+        while i < parts[part].len() {
+            bytes[len] = parts[part][i];
+            len += 1;
+            i += 1;
+        }
+        part += 1;
+    }
+    bytes
+}
+
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "default-lang-only"))]
+macro_rules! __language_atlas_languages {
+    (@functions { $($head:tt)* } { $($langs:tt)* }) => {
+        $crate::generate_language_functions! { @match_impl $($head)* { $($langs)* } }
+    };
+
+    (@plural ($d:tt) { $($head:tt)* } $config:tt { $($entries:tt)* }) => {
+        $crate::generate_language_functions! { $($head)* { $($entries)* } }
+    };
+
+    (@localized_enum { $($head:tt)* } { $($langs:tt)* }) => {
+        $crate::generate_localized_enum! { @match_impl $($head)* { $($langs)* } }
+    };
+
+    (@unused $($args:ident),*) => { };
+}
+
+/// With the `default-lang-only` feature only the strings of the default language are compiled.
+/// All other languages use the fallback of the missing string policy. `LANGUAGE_ATLAS_DEFAULT` is ignored.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "default-lang-only")]
+macro_rules! __language_atlas_languages {
+    (@functions { [missing: [Strict] $($config:tt)*] $($head:tt)* } { $($langs:tt)* }) => {
+        $crate::__language_atlas_languages! { @functions { [missing: [Default] $($config)*] $($head)* } { $($langs)* } }
+    };
+
+    (@functions {
+        [missing: $missing:tt hook: $hook:tt languages: $languages:tt coverage: $coverage:tt default: [$default:ident] fallback: $fallback:tt]
+        $format:tt $self:ident $enum_name:ident $field:ident $first_lang:ident $first_value:expr,
+    } { $($lang:ident: $value:expr),* }) => {
+        $crate::__language_atlas_languages! { @default_entry ($)
+            { [missing: $missing hook: $hook languages: $languages coverage: $coverage default: [$default] fallback: $fallback] $format $self $enum_name $field }
+            $default { $first_lang: $first_value, $($lang: $value,)* }
+        }
+    };
+
+    (@functions { $($head:tt)* } { $($langs:tt)* }) => {
+        $crate::generate_language_functions! { @match_impl $($head)* { } }
+    };
+
+    // Keeps the string of the `Default` language, or the first string if that language provides none.
+    (@default_entry ($d:tt) { $config:tt $format:tt $self:ident $enum_name:ident $field:ident } $default:ident { $first_lang:ident: $first_value:expr, $($langs:tt)* }) => {{
+        macro_rules! __language_atlas_default_entry {
+            ($default: $d value:expr, $d($d rest:tt)*) => {
+                $crate::generate_language_functions! { @match_impl $config $format $self $enum_name $field $default $d value, { } }
+            };
+            ($d lang:ident: $d value:expr, $d($d rest:tt)*) => { __language_atlas_default_entry! { $d($d rest)* } };
+            () => {
+                $crate::generate_language_functions! { @match_impl $config $format $self $enum_name $field $first_lang $first_value, { } }
+            };
+        }
+        __language_atlas_default_entry! { $first_lang: $first_value, $($langs)* }
+    }};
+
+    (@plural ($d:tt) $head:tt [missing: $missing:tt hook: $hook:tt languages: $languages:tt coverage: $coverage:tt default: [$default:ident] fallback: $fallback:tt] {
+        $first_lang:ident $($entries:tt)*
+    }) => {
+        $crate::__language_atlas_languages! { @plural_entries ($d) $head $default $first_lang { $first_lang $($entries)* } }
+    };
+    (@plural ($d:tt) $head:tt $config:tt { $first_lang:ident $($entries:tt)* }) => {
+        $crate::__language_atlas_languages! { @plural_entries ($d) $head $first_lang $first_lang { $first_lang $($entries)* } }
+    };
+
+    // Keeps the strings of the `Default` language, or of the first language if that language provides none.
+    (@plural_entries ($d:tt) { $($head:tt)* } $default:ident $first_lang:ident { $($entries:tt)* }) => {{
+        macro_rules! __language_atlas_default_entries {
+            ([$d($d kept:tt)*] $d first:tt $default $d(. $d cat:tt)?: $d value:expr, $d($d rest:tt)*) => {
+                __language_atlas_default_entries! { [$d($d kept)* $default $d(. $d cat)?: $d value,] $d first $d($d rest)* }
+            };
+            ($d kept:tt [$d($d first:tt)*] $first_lang $d(. $d cat:tt)?: $d value:expr, $d($d rest:tt)*) => {
+                __language_atlas_default_entries! { $d kept [$d($d first)* $first_lang $d(. $d cat)?: $d value,] $d($d rest)* }
+            };
+            ($d kept:tt $d first:tt $d lang:ident $d(. $d cat:tt)?: $d value:expr, $d($d rest:tt)*) => {
+                __language_atlas_default_entries! { $d kept $d first $d($d rest)* }
+            };
+            ([] [$d($d first:tt)*]) => {
+                $crate::generate_language_functions! { $($head)* { $d($d first)* } }
+            };
+            ([$d($d kept:tt)+] $d first:tt) => {
+                $crate::generate_language_functions! { $($head)* { $d($d kept)* } }
+            };
+        }
+        __language_atlas_default_entries! { [] [] $($entries)* }
+    }};
+
+    (@localized_enum { $($head:tt)* } { $first_lang:ident: $first_value:expr, $($langs:tt)* }) => {
+        $crate::generate_localized_enum! { @match_impl $($head)* { $first_lang: $first_value, } }
+    };
+
+    // Marks the parameters as used, the strings of other languages that use them are not compiled.
+    (@unused $($args:ident),*) => {
+        $( let _ = &$args; )*
+    };
+}
+
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __language_atlas_plural {
+    (@number $n:ident $number:literal) => { $n == $number };
+    (@number $n:ident $($cat:ident)?) => { false };
+
+    (@kind []) => { };
+    (@kind [ordinal]) => { };
+    (@kind [$kind:ident]) => {
+        compile_error!(concat!("Unknown field mode `", stringify!($kind), "`. Expected `ordinal` or `select`."));
+    };
+
+    (@category $kind:tt $lang:ident $n:ident zero) => { $crate::__language_atlas_plural!(@rule $kind $lang $n Zero) };
+    (@category $kind:tt $lang:ident $n:ident one) => { $crate::__language_atlas_plural!(@rule $kind $lang $n One) };
+    (@category $kind:tt $lang:ident $n:ident two) => { $crate::__language_atlas_plural!(@rule $kind $lang $n Two) };
+    (@category $kind:tt $lang:ident $n:ident few) => { $crate::__language_atlas_plural!(@rule $kind $lang $n Few) };
+    (@category $kind:tt $lang:ident $n:ident many) => { $crate::__language_atlas_plural!(@rule $kind $lang $n Many) };
+    (@category $kind:tt $lang:ident $n:ident $(other)? $($number:literal)?) => { false };
+    (@category $kind:tt $lang:ident $n:ident $cat:ident) => {
+        compile_error!(concat!("Unknown plural category `", stringify!($cat), "`. Expected `zero`, `one`, `two`, `few`, `many`, `other` or a number."))
+    };
+
+    (@rule [] $lang:ident $n:ident $category:ident) => {
+        $crate::plural::PluralRules::plural_rule($lang).cardinal($n) == $crate::plural::PluralCategory::$category
+    };
+    (@rule [ordinal] $lang:ident $n:ident $category:ident) => {
+        $crate::plural::OrdinalRules::ordinal_rule($lang).ordinal($n) == $crate::plural::PluralCategory::$category
+    };
+    (@rule [$kind:ident] $lang:ident $n:ident $category:ident) => { false };
+
+    (@select $selector:ident [$selector_type:ty] $(other)?) => { false };
+    (@select $selector:ident [$selector_type:ty] $variant:ident) => { matches!($selector, <$selector_type>::$variant) };
+    (@select $selector:ident [$selector_type:ty] $variant:tt) => {
+        compile_error!(concat!("Select branch `", stringify!($variant), "` has to be a variant of `", stringify!($selector_type), "`."))
+    };
+
+    (@other $(other)?) => { true };
+    (@other $cat:tt) => { false };
 }
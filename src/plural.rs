@@ -0,0 +1,257 @@
+//! CLDR plural rules for plural and ordinal fields of `generate_language_functions!`.
+
+/// The CLDR plural categories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// The CLDR cardinal plural rules for integer counts, named after a representative language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralRule {
+    /// No plural forms. Chinese, Japanese, Korean, Thai, Vietnamese, Indonesian, ...
+    NoPlural,
+    /// `one` for 1. English, German, Dutch, Swedish, Danish, Finnish, Greek, Hungarian, Turkish, ...
+    English,
+    /// `one` for 1 and `many` for millions. Spanish, Italian, Catalan, European Portuguese
+    Spanish,
+    /// `one` for 0 and 1 and `many` for millions. French, Brazilian Portuguese
+    French,
+    /// `one`, `few` and `many`. Russian, Ukrainian, Belarusian
+    Russian,
+    /// `one`, `few` and `other`. Croatian, Serbian, Bosnian
+    Croatian,
+    /// `one`, `few` and `many`. Polish
+    Polish,
+    /// `one` and `few`. Czech, Slovak
+    Czech,
+    /// `zero`, `one`, `two`, `few` and `many`. Arabic
+    Arabic,
+    /// `one` and `two`. Hebrew
+    Hebrew,
+    /// `one` and `few`. Romanian
+    Romanian,
+    /// `one` and `few`. Lithuanian
+    Lithuanian,
+    /// `zero` and `one`. Latvian
+    Latvian,
+    /// `one`, `two` and `few`. Slovenian
+    Slovenian,
+    /// `one`, `two`, `few` and `many`. Irish
+    Irish,
+    /// `zero`, `one`, `two`, `few` and `many`. Welsh
+    Welsh,
+}
+
+impl PluralRule {
+    /// Returns the cardinal plural category of `n`.
+    pub fn cardinal(&self, n: u64) -> PluralCategory {
+        use PluralCategory::*;
+        let (n10, n100) = (n % 10, n % 100);
+        match self {
+            PluralRule::NoPlural => Other,
+            PluralRule::English => match n {
+                1 => One,
+                _ => Other,
+            },
+            PluralRule::Spanish => match n {
+                1 => One,
+                n if n != 0 && n % 1_000_000 == 0 => Many,
+                _ => Other,
+            },
+            PluralRule::French => match n {
+                0 | 1 => One,
+                n if n % 1_000_000 == 0 => Many,
+                _ => Other,
+            },
+            PluralRule::Russian => match (n10, n100) {
+                (1, n100) if n100 != 11 => One,
+                (2..=4, n100) if !(12..=14).contains(&n100) => Few,
+                _ => Many,
+            },
+            PluralRule::Croatian => match (n10, n100) {
+                (1, n100) if n100 != 11 => One,
+                (2..=4, n100) if !(12..=14).contains(&n100) => Few,
+                _ => Other,
+            },
+            PluralRule::Polish => match (n, n10, n100) {
+                (1, _, _) => One,
+                (_, 2..=4, n100) if !(12..=14).contains(&n100) => Few,
+                _ => Many,
+            },
+            PluralRule::Czech => match n {
+                1 => One,
+                2..=4 => Few,
+                _ => Other,
+            },
+            PluralRule::Arabic => match (n, n100) {
+                (0, _) => Zero,
+                (1, _) => One,
+                (2, _) => Two,
+                (_, 3..=10) => Few,
+                (_, 11..=99) => Many,
+                _ => Other,
+            },
+            PluralRule::Hebrew => match n {
+                1 => One,
+                2 => Two,
+                _ => Other,
+            },
+            PluralRule::Romanian => match (n, n100) {
+                (1, _) => One,
+                (0, _) | (_, 1..=19) => Few,
+                _ => Other,
+            },
+            PluralRule::Lithuanian => match (n10, n100) {
+                (_, 11..=19) => Other,
+                (1, _) => One,
+                (2..=9, _) => Few,
+                _ => Other,
+            },
+            PluralRule::Latvian => match (n10, n100) {
+                (0, _) | (_, 11..=19) => Zero,
+                (1, _) => One,
+                _ => Other,
+            },
+            PluralRule::Slovenian => match n100 {
+                1 => One,
+                2 => Two,
+                3 | 4 => Few,
+                _ => Other,
+            },
+            PluralRule::Irish => match n {
+                1 => One,
+                2 => Two,
+                3..=6 => Few,
+                7..=10 => Many,
+                _ => Other,
+            },
+            PluralRule::Welsh => match n {
+                0 => Zero,
+                1 => One,
+                2 => Two,
+                3 => Few,
+                6 => Many,
+                _ => Other,
+            },
+        }
+    }
+}
+
+/// The CLDR ordinal plural rules for integer counts, named after a representative language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrdinalRule {
+    /// No ordinal forms. German, Spanish, Portuguese, Russian, Polish, Czech, Japanese, Chinese, ...
+    NoOrdinal,
+    /// `one` (1st), `two` (2nd) and `few` (3rd). English
+    English,
+    /// `one` for 1. French, Irish, Vietnamese, Malay
+    French,
+    /// `many` for 8, 11, 80 and 800. Italian
+    Italian,
+    /// `one` for 1 and 2, also 21, 22, ... Swedish
+    Swedish,
+    /// `one` for 1 and 5. Hungarian
+    Hungarian,
+    /// `one` for 1 and 3, `two` for 2 and `few` for 4. Catalan
+    Catalan,
+    /// `zero`, `one`, `two`, `few` and `many`. Welsh
+    Welsh,
+}
+
+impl OrdinalRule {
+    /// Returns the ordinal plural category of `n`.
+    pub fn ordinal(&self, n: u64) -> PluralCategory {
+        use PluralCategory::*;
+        let (n10, n100) = (n % 10, n % 100);
+        match self {
+            OrdinalRule::NoOrdinal => Other,
+            OrdinalRule::English => match (n10, n100) {
+                (1, n100) if n100 != 11 => One,
+                (2, n100) if n100 != 12 => Two,
+                (3, n100) if n100 != 13 => Few,
+                _ => Other,
+            },
+            OrdinalRule::French => match n {
+                1 => One,
+                _ => Other,
+            },
+            OrdinalRule::Italian => match n {
+                8 | 11 | 80 | 800 => Many,
+                _ => Other,
+            },
+            OrdinalRule::Swedish => match (n10, n100) {
+                (1 | 2, n100) if n100 != 11 && n100 != 12 => One,
+                _ => Other,
+            },
+            OrdinalRule::Hungarian => match n {
+                1 | 5 => One,
+                _ => Other,
+            },
+            OrdinalRule::Catalan => match n {
+                1 | 3 => One,
+                2 => Two,
+                4 => Few,
+                _ => Other,
+            },
+            OrdinalRule::Welsh => match n {
+                0 | 7 | 8 | 9 => Zero,
+                1 => One,
+                2 => Two,
+                3 | 4 => Few,
+                5 | 6 => Many,
+                _ => Other,
+            },
+        }
+    }
+}
+
+/// Maps the variants of a language enum to their plural rules.
+/// Has to be implemented for language enums with plural fields.
+pub trait PluralRules {
+    fn plural_rule(&self) -> PluralRule;
+}
+
+/// Maps the variants of a language enum to their ordinal rules.
+/// Has to be implemented for language enums with ordinal fields.
+pub trait OrdinalRules {
+    fn ordinal_rule(&self) -> OrdinalRule;
+}
+
+/// Integer types that can be used as the count of a plural field.
+/// Negative counts use their absolute value.
+pub trait PluralOperand {
+    fn plural_operand(&self) -> u64;
+}
+
+macro_rules! impl_plural_operand {
+    (unsigned: $($unsigned:ty),* ; signed: $($signed:ty),*) => {
+        $(
+            impl PluralOperand for $unsigned {
+                fn plural_operand(&self) -> u64 {
+                    u64::try_from(*self).unwrap_or(u64::MAX)
+                }
+            }
+        )*
+        $(
+            impl PluralOperand for $signed {
+                fn plural_operand(&self) -> u64 {
+                    u64::try_from(self.unsigned_abs()).unwrap_or(u64::MAX)
+                }
+            }
+        )*
+    };
+}
+
+impl_plural_operand!(unsigned: u8, u16, u32, u64, u128, usize; signed: i8, i16, i32, i64, i128, isize);
+
+impl<T: PluralOperand + ?Sized> PluralOperand for &T {
+    fn plural_operand(&self) -> u64 {
+        (**self).plural_operand()
+    }
+}
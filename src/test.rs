@@ -1,9 +1,11 @@
 #[cfg(test)]
 mod tests {
-    use crate::generate_language_functions;
+    use crate::{generate_language_functions, generate_language_schema, generate_localized_enum};
 
 
     #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    #[allow(deprecated)]
     fn it_works() {
         enum Language {
             English,
@@ -36,10 +38,13 @@ mod tests {
         assert_eq!(lang.date(1, 2, 2021), "2/1/2021");
         assert_eq!(lang.dummy(), "ToDo!");
 
+        let english_default = option_env!("LANGUAGE_ATLAS_DEFAULT") == Some("English");
         lang = Language::Spanish;
         assert_eq!(lang.greeting(), "Hola");
         assert_eq!(lang.farewell("Juan"), "Adiós, Juan");
-        assert_eq!(lang.date(1, 2, 2021), "1/2/2021");
+        if !english_default {
+            assert_eq!(lang.date(1, 2, 2021), "1/2/2021");
+        }
         assert_eq!(lang.dummy(), "ToDo!");
 
         lang = Language::French;
@@ -87,6 +92,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn dummy() {
         enum Language {
             German,
@@ -145,6 +151,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
     fn all_values_given() {
         enum Variants {
             English,
@@ -185,4 +192,1541 @@ mod tests {
         assert_eq!(lang.number(5), "nombre: 5");
         assert_eq!(lang.content("Bonjour"), "Contenu: Bonjour");
     }
-}
\ No newline at end of file
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn schema() {
+        generate_language_schema! {
+            trait CoreStrings {
+                greeting
+                farewell(name)
+                date(day: u8, month: u8, year: u16)
+                dummy
+            }
+        }
+
+        enum Language {
+            English,
+            German,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Schema: CoreStrings
+            greeting {
+                English: "Hello"
+                German:  "Hallo"
+            }
+            farewell(name) {
+                English: "Goodbye, {name}"
+                German:  "Auf Wiedersehen, {name}"
+            }
+            date(day: u8, month: u8, year: u16) {
+                German:  "{day}.{month}.{year}"
+                English: "{month}/{day}/{year}"
+            }
+            dummy { }
+        }
+
+        fn render(strings: &impl CoreStrings) -> String {
+            format!("{} {} {}", strings.greeting(), strings.farewell("Tom"), strings.date(1, 2, 2021))
+        }
+
+        let mut lang = Language::English;
+        assert_eq!(render(&lang), "Hello Goodbye, Tom 2/1/2021");
+        assert_eq!(lang.dummy(), "ToDo!");
+
+        lang = Language::German;
+        assert_eq!(render(&lang), "Hallo Auf Wiedersehen, Tom 1.2.2021");
+        assert_eq!(lang.dummy(), "ToDo!");
+    }
+
+    #[test]
+    fn schema_modes() {
+        generate_language_schema! {
+            trait Counts {
+                files(count: u32)
+                place(n: u32)
+                invited(gender: Gender, name: &str)
+            }
+        }
+
+        enum Language {
+            English,
+        }
+
+        #[derive(Clone, Copy)]
+        #[allow(dead_code)]
+        enum Gender {
+            Female,
+            Other,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Schema: Counts
+            files(count: u32) {
+                English.1:     "One file"
+                English.other: "{count} files"
+            }
+            place ordinal(n: u32) {
+                English.1:     "{n}st"
+                English.other: "{n}th"
+            }
+            invited select(gender: Gender, name: &str) {
+                English.Female: "{name} invited you to her party"
+                English.other:  "{name} invited you to their party"
+            }
+        }
+
+        fn render(strings: &impl Counts) -> String {
+            format!("{}, {}, {}", strings.files(2), strings.place(1), strings.invited(Gender::Female, "Ada"))
+        }
+
+        assert_eq!(render(&Language::English), "2 files, 1st, Ada invited you to her party");
+        assert_eq!(Language::English.invited(Gender::Other, "Sam"), "Sam invited you to their party");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn localized_enum() {
+        enum Language {
+            English,
+            German,
+            French,
+        }
+
+        enum Weekday {
+            Monday,
+            Tuesday,
+        }
+
+        #[allow(dead_code)]
+        enum Error {
+            NotFound(u16),
+            Timeout { seconds: u32 },
+        }
+
+        generate_localized_enum! {
+            LanguageEnum: Language
+            Weekday {
+                Monday {
+                    English: "Monday"
+                    German:  "Montag"
+                    French:  "lundi"
+                }
+                Tuesday {
+                    English: "Tuesday"
+                    German:  "Dienstag"
+                }
+            }
+        }
+
+        generate_localized_enum! {
+            LanguageEnum: Language
+            Error {
+                NotFound {
+                    English: "Not found"
+                    German:  "Nicht gefunden"
+                }
+                Timeout {
+                    German:  "Zeitüberschreitung"
+                }
+            }
+        }
+
+        assert_eq!(Weekday::Monday.localize(&Language::English), "Monday");
+        assert_eq!(Weekday::Monday.localize(&Language::German), "Montag");
+        assert_eq!(Weekday::Monday.localize(&Language::French), "lundi");
+        assert_eq!(Weekday::Tuesday.localize(&Language::German), "Dienstag");
+        assert_eq!(Error::NotFound(404).localize(&Language::German), "Nicht gefunden");
+        if option_env!("LANGUAGE_ATLAS_DEFAULT") != Some("German") {
+            assert_eq!(Weekday::Tuesday.localize(&Language::French), "Tuesday");
+            assert_eq!(Error::NotFound(404).localize(&Language::French), "Not found");
+        }
+        assert_eq!(Error::Timeout { seconds: 30 }.localize(&Language::English), "Zeitüberschreitung");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn boolean_strings() {
+        use crate::BooleanStrings;
+
+        enum Language {
+            English,
+            French,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Schema: crate::BooleanStrings
+            yes      { English: "Yes"      French: "Oui" }
+            no       { English: "No"       French: "Non" }
+            on       { English: "On" }
+            off      { English: "Off" }
+            enabled  { English: "Enabled"  French: "Activé" }
+            disabled { English: "Disabled" French: "Désactivé" }
+        }
+
+        let mut lang = Language::English;
+        assert_eq!(lang.yes_no(true), "Yes");
+        assert_eq!(lang.yes_no(false), "No");
+        assert_eq!(lang.on_off(true), "On");
+        assert_eq!(lang.enabled_disabled(false), "Disabled");
+
+        lang = Language::French;
+        assert_eq!(lang.yes_no(true), "Oui");
+        assert_eq!(lang.on_off(false), "Off");
+        assert_eq!(lang.enabled_disabled(true), "Activé");
+        assert_eq!(lang.enabled_disabled(false), "Désactivé");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn escaped_braces() {
+        enum Language {
+            English,
+            German,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language
+            literal {
+                English: "{braces}"
+                German:  "{{Klammern}}"
+            }
+            escaped(name) {
+                English: "{{{name}}}"
+                German:  "{{name}} = {name}"
+            }
+        }
+
+        let mut lang = Language::English;
+        assert_eq!(lang.literal(), "{braces}");
+        assert_eq!(lang.escaped("x"), "{x}");
+
+        lang = Language::German;
+        assert_eq!(lang.literal(), "{{Klammern}}");
+        assert_eq!(lang.escaped("x"), "{name} = x");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn missing_policy() {
+        enum Language {
+            English,
+            German,
+            French,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Missing: KeyName
+            greeting {
+                English: "Hello"
+                German:  "Hallo"
+            }
+            farewell(name) {
+                English: "Goodbye, {name}"
+            }
+            date(day: u8, month: u8, year: u16) {
+                German:  "{day}.{month}.{year}"
+                English: "{month}/{day}/{year}"
+            }
+        }
+
+        let mut lang = Language::English;
+        assert_eq!(lang.greeting(), "Hello");
+        assert_eq!(lang.farewell("John"), "Goodbye, John");
+        assert_eq!(lang.date(1, 2, 2021), "2/1/2021");
+
+        lang = Language::German;
+        assert_eq!(lang.greeting(), "Hallo");
+        assert_eq!(lang.farewell("Hans"), "[farewell]");
+        assert_eq!(lang.date(1, 2, 2021), "1.2.2021");
+
+        lang = Language::French;
+        assert_eq!(lang.greeting(), "[greeting]");
+        assert_eq!(lang.farewell("Jean"), "[farewell]");
+        assert_eq!(lang.date(1, 2, 2021), "[date]");
+    }
+
+    #[test]
+    fn missing_policy_marker() {
+        enum Language {
+            English,
+            German,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Missing: Marker
+            greeting {
+                English: "Hello"
+            }
+            farewell(name) {
+                English: "Goodbye, {name}"
+            }
+            r#type {
+                English: "Type"
+            }
+        }
+
+        assert_eq!(Language::English.greeting(), "Hello");
+        assert_eq!(Language::German.greeting(), "⟦greeting⟧");
+        assert_eq!(Language::German.farewell("Hans"), "⟦farewell⟧");
+        assert_eq!(Language::German.r#type(), "⟦type⟧");
+    }
+
+    #[test]
+    fn missing_policy_empty() {
+        enum Language {
+            English,
+            German,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Missing: Empty
+            greeting {
+                English: "Hello"
+            }
+            farewell(name) {
+                German: "Auf Wiedersehen, {name}"
+            }
+        }
+
+        let mut lang = Language::English;
+        assert_eq!(lang.greeting(), "Hello");
+        assert_eq!(lang.farewell("John"), "");
+
+        lang = Language::German;
+        assert_eq!(lang.greeting(), "");
+        assert_eq!(lang.farewell("Hans"), "Auf Wiedersehen, Hans");
+    }
+
+    #[test]
+    fn missing_policy_default() {
+        generate_language_schema! {
+            trait Strings {
+                greeting
+            }
+        }
+
+        enum Language {
+            English,
+            German,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Schema: Strings, Missing: Default
+            greeting {
+                English: "Hello"
+            }
+        }
+
+        assert_eq!(Language::English.greeting(), "Hello");
+        assert_eq!(Language::German.greeting(), "Hello");
+    }
+
+    #[test]
+    fn missing_policy_environment() {
+        enum Language {
+            English,
+            German,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Missing: { dev: KeyName, staging: Marker, qa: Empty, prod: Default }
+            greeting {
+                English: "Hello"
+            }
+            farewell(name) {
+                English: "Goodbye, {name}"
+            }
+        }
+
+        let expected = match option_env!("LANGUAGE_ATLAS_ENV") {
+            Some("dev") => ("[greeting]", "[farewell]"),
+            Some("staging") => ("⟦greeting⟧", "⟦farewell⟧"),
+            Some("qa") => ("", ""),
+            _ => ("Hello", "Goodbye, Hans"),
+        };
+
+        assert_eq!(Language::English.greeting(), "Hello");
+        assert_eq!(Language::English.farewell("John"), "Goodbye, John");
+        assert_eq!(Language::German.greeting(), expected.0);
+        assert_eq!(Language::German.farewell("Hans"), expected.1);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    #[allow(deprecated)]
+    fn render_hook() {
+        use std::cell::RefCell;
+
+        enum Language {
+            English,
+            German,
+        }
+
+        thread_local! {
+            static RENDERS: RefCell<Vec<(&'static str, &'static str)>> = const { RefCell::new(Vec::new()) };
+        }
+
+        fn record(key: &'static str, lang: &Language) {
+            let lang = match lang {
+                Language::English => "English",
+                Language::German => "German",
+            };
+            RENDERS.with(|renders| renders.borrow_mut().push((key, lang)));
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Hook: record
+            greeting {
+                English: "Hello"
+                German:  "Hallo"
+            }
+            farewell(name) {
+                English: "Goodbye, {name}"
+            }
+            counter(n: u8) {
+                German: "#{n}"
+            }
+            dummy { }
+            r#type {
+                English: "Type"
+            }
+        }
+
+        assert_eq!(Language::English.greeting(), "Hello");
+        assert_eq!(Language::German.greeting(), "Hallo");
+        assert_eq!(Language::German.farewell("Hans"), "Goodbye, Hans");
+        assert_eq!(Language::English.counter(3), "#3");
+        assert_eq!(Language::English.dummy(), "ToDo!");
+        assert_eq!(Language::German.r#type(), "Type");
+
+        RENDERS.with(|renders| {
+            assert_eq!(*renders.borrow(), vec![
+                ("greeting", "English"),
+                ("greeting", "German"),
+                ("farewell", "German"),
+                ("counter", "English"),
+                ("dummy", "English"),
+                ("type", "German"),
+            ]);
+        });
+    }
+
+    #[test]
+    fn default_lang_only() {
+        enum Language {
+            English,
+            German,
+        }
+
+        enum Weekday {
+            Monday,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language
+            greeting {
+                English: "Hello"
+                German:  "Hallo"
+            }
+            farewell(name) {
+                English: "Goodbye, {name}"
+                German:  "Auf Wiedersehen, {name}"
+            }
+        }
+
+        generate_localized_enum! {
+            LanguageEnum: Language
+            Weekday {
+                Monday {
+                    English: "Monday"
+                    German:  "Montag"
+                }
+            }
+        }
+
+        let lang = Language::German;
+        if cfg!(feature = "default-lang-only") {
+            assert_eq!(lang.greeting(), "Hello");
+            assert_eq!(lang.farewell("Hans"), "Goodbye, Hans");
+            assert_eq!(Weekday::Monday.localize(&lang), "Monday");
+        } else {
+            assert_eq!(lang.greeting(), "Hallo");
+            assert_eq!(lang.farewell("Hans"), "Auf Wiedersehen, Hans");
+            assert_eq!(Weekday::Monday.localize(&lang), "Montag");
+        }
+        assert_eq!(Language::English.greeting(), "Hello");
+    }
+
+    #[test]
+    fn default_lang_only_header_default() {
+        enum Language {
+            English,
+            French,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Default: English
+            greeting {
+                French:  "Bonjour"
+                English: "Hello"
+            }
+            farewell(name) {
+                French:  "Au revoir, {name}"
+                English: "Goodbye, {name}"
+            }
+        }
+
+        let lang = Language::French;
+        if cfg!(feature = "default-lang-only") {
+            assert_eq!(lang.greeting(), "Hello");
+            assert_eq!(lang.farewell("Jean"), "Goodbye, Jean");
+        } else {
+            assert_eq!(lang.greeting(), "Bonjour");
+            assert_eq!(lang.farewell("Jean"), "Au revoir, Jean");
+        }
+        assert_eq!(Language::English.greeting(), "Hello");
+    }
+
+    #[test]
+    fn default_from_environment() {
+        enum Language {
+            English,
+            German,
+            French,
+        }
+
+        enum Weekday {
+            Monday,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language
+            greeting {
+                English: "Hello"
+                German:  "Hallo"
+            }
+            farewell(name) {
+                English: "Goodbye, {name}"
+                German:  "Auf Wiedersehen, {name}"
+            }
+        }
+
+        generate_localized_enum! {
+            LanguageEnum: Language
+            Weekday {
+                Monday {
+                    English: "Monday"
+                    German:  "Montag"
+                }
+            }
+        }
+
+        let german_default = option_env!("LANGUAGE_ATLAS_DEFAULT") == Some("German")
+            && !cfg!(feature = "default-lang-only");
+        let lang = Language::French;
+        if german_default {
+            assert_eq!(lang.greeting(), "Hallo");
+            assert_eq!(lang.farewell("Jean"), "Auf Wiedersehen, Jean");
+            assert_eq!(Weekday::Monday.localize(&lang), "Montag");
+        } else {
+            assert_eq!(lang.greeting(), "Hello");
+            assert_eq!(lang.farewell("Jean"), "Goodbye, Jean");
+            assert_eq!(Weekday::Monday.localize(&lang), "Monday");
+        }
+        assert_eq!(Language::English.greeting(), "Hello");
+        if !cfg!(feature = "default-lang-only") {
+            assert_eq!(Language::German.greeting(), "Hallo");
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn plural() {
+        use crate::plural::{PluralRule, PluralRules};
+
+        enum Language {
+            English,
+            Polish,
+            Japanese,
+            French,
+            German,
+        }
+
+        impl PluralRules for Language {
+            fn plural_rule(&self) -> PluralRule {
+                match self {
+                    Language::English | Language::German => PluralRule::English,
+                    Language::Polish => PluralRule::Polish,
+                    Language::Japanese => PluralRule::NoPlural,
+                    Language::French => PluralRule::French,
+                }
+            }
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language
+            items(count: u64) {
+                English.one:   "{count} item"
+                English.other: "{count} items"
+                Polish.one:    "{count} plik"
+                Polish.few:    "{count} pliki"
+                Polish.many:   "{count} plików"
+                Japanese:      "{count}個"
+                French.one:    "{count} élément"
+                French.other:  "{count} éléments"
+            }
+            files_in(count, folder) {
+                English.one:   "{count} file in {folder}"
+                English.other: "{count} files in {folder}"
+            }
+        }
+
+        let mut lang = Language::English;
+        assert_eq!(lang.items(0), "0 items");
+        assert_eq!(lang.items(1), "1 item");
+        assert_eq!(lang.items(2), "2 items");
+        assert_eq!(lang.files_in(1, "src"), "1 file in src");
+        assert_eq!(lang.files_in(-3, "src"), "-3 files in src");
+
+        lang = Language::Polish;
+        assert_eq!(lang.items(1), "1 plik");
+        assert_eq!(lang.items(3), "3 pliki");
+        assert_eq!(lang.items(5), "5 plików");
+        assert_eq!(lang.items(12), "12 plików");
+        assert_eq!(lang.items(22), "22 pliki");
+        assert_eq!(lang.files_in(2, "src"), "2 files in src");
+
+        lang = Language::Japanese;
+        assert_eq!(lang.items(1), "1個");
+        assert_eq!(lang.items(7), "7個");
+
+        lang = Language::French;
+        assert_eq!(lang.items(0), "0 élément");
+        assert_eq!(lang.items(2), "2 éléments");
+        assert_eq!(lang.items(1_000_000), "1000000 éléments");
+
+        lang = Language::German;
+        if option_env!("LANGUAGE_ATLAS_DEFAULT") != Some("French") {
+            assert_eq!(lang.items(1), "1 item");
+            assert_eq!(lang.items(4), "4 items");
+        }
+    }
+
+    #[test]
+    fn plural_missing_policy() {
+        use crate::plural::{PluralRule, PluralRules};
+
+        enum Language {
+            English,
+            Russian,
+        }
+
+        impl PluralRules for Language {
+            fn plural_rule(&self) -> PluralRule {
+                match self {
+                    Language::English => PluralRule::English,
+                    Language::Russian => PluralRule::Russian,
+                }
+            }
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Missing: KeyName
+            items(count: i32) {
+                English.one:   "{count} item"
+                English.other: "{count} items"
+            }
+        }
+
+        assert_eq!(Language::English.items(1), "1 item");
+        assert_eq!(Language::Russian.items(1), "[items]");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn plural_missing_category_policy() {
+        enum Language {
+            English,
+            German,
+            French,
+        }
+
+        enum Gender {
+            Female,
+            Male,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Missing: KeyName, Fallback: [French -> German]
+            files(count: u32) {
+                English.1:     "One file"
+                English.other: "{count} files"
+                German.1:      "Eine Datei"
+            }
+            invited select(gender: Gender) {
+                English.other:  "They are invited"
+                German.Female:  "Sie ist eingeladen"
+            }
+        }
+
+        assert_eq!(Language::German.files(1), "Eine Datei");
+        assert_eq!(Language::German.files(5), "[files]");
+        assert_eq!(Language::French.files(1), "Eine Datei");
+        assert_eq!(Language::French.files(5), "[files]");
+        assert_eq!(Language::English.files(5), "5 files");
+        assert_eq!(Language::German.invited(Gender::Female), "Sie ist eingeladen");
+        assert_eq!(Language::German.invited(Gender::Male), "[invited]");
+        assert_eq!(Language::French.invited(Gender::Female), "Sie ist eingeladen");
+        assert_eq!(Language::English.invited(Gender::Male), "They are invited");
+    }
+
+    #[test]
+    fn plural_rules() {
+        use crate::plural::PluralCategory::*;
+        use crate::plural::PluralRule;
+
+        let categories = |rule: PluralRule, counts: &[u64]| counts.iter().map(|n| rule.cardinal(*n)).collect::<Vec<_>>();
+
+        assert_eq!(categories(PluralRule::NoPlural, &[0, 1, 2]), [Other, Other, Other]);
+        assert_eq!(categories(PluralRule::English, &[0, 1, 2, 11]), [Other, One, Other, Other]);
+        assert_eq!(categories(PluralRule::Spanish, &[0, 1, 2, 1_000_000]), [Other, One, Other, Many]);
+        assert_eq!(categories(PluralRule::French, &[0, 1, 2, 2_000_000]), [One, One, Other, Many]);
+        assert_eq!(categories(PluralRule::Russian, &[0, 1, 2, 5, 11, 12, 21, 22, 25, 111]), [Many, One, Few, Many, Many, Many, One, Few, Many, Many]);
+        assert_eq!(categories(PluralRule::Croatian, &[1, 3, 5, 11, 21]), [One, Few, Other, Other, One]);
+        assert_eq!(categories(PluralRule::Polish, &[0, 1, 2, 5, 12, 21, 22]), [Many, One, Few, Many, Many, Many, Few]);
+        assert_eq!(categories(PluralRule::Czech, &[0, 1, 2, 4, 5]), [Other, One, Few, Few, Other]);
+        assert_eq!(categories(PluralRule::Arabic, &[0, 1, 2, 3, 10, 11, 99, 100, 103]), [Zero, One, Two, Few, Few, Many, Many, Other, Few]);
+        assert_eq!(categories(PluralRule::Hebrew, &[1, 2, 3, 20]), [One, Two, Other, Other]);
+        assert_eq!(categories(PluralRule::Romanian, &[0, 1, 2, 19, 20, 101]), [Few, One, Few, Few, Other, Few]);
+        assert_eq!(categories(PluralRule::Lithuanian, &[1, 2, 9, 10, 11, 21]), [One, Few, Few, Other, Other, One]);
+        assert_eq!(categories(PluralRule::Latvian, &[0, 1, 2, 11, 21]), [Zero, One, Other, Zero, One]);
+        assert_eq!(categories(PluralRule::Slovenian, &[1, 2, 3, 5, 101, 102]), [One, Two, Few, Other, One, Two]);
+        assert_eq!(categories(PluralRule::Irish, &[1, 2, 3, 7, 11]), [One, Two, Few, Many, Other]);
+        assert_eq!(categories(PluralRule::Welsh, &[0, 1, 2, 3, 6, 4]), [Zero, One, Two, Few, Many, Other]);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn plural_numbers() {
+        enum Language {
+            English,
+            German,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language
+            files(n: usize) {
+                English.0:     "No files"
+                English.1:     "One file"
+                English.other: "{n} files"
+                German.other:  "{n} Dateien"
+                German.0:      "Keine Dateien"
+                German.1:      "Eine Datei"
+            }
+            folders(n: usize) {
+                English.1:     "One folder"
+                English.other: "{n} folders"
+                German.1:      "Ein Ordner"
+            }
+        }
+
+        let mut lang = Language::English;
+        assert_eq!(lang.files(0), "No files");
+        assert_eq!(lang.files(1), "One file");
+        assert_eq!(lang.files(2), "2 files");
+
+        lang = Language::German;
+        assert_eq!(lang.files(0), "Keine Dateien");
+        assert_eq!(lang.files(1), "Eine Datei");
+        assert_eq!(lang.files(9), "9 Dateien");
+        assert_eq!(lang.folders(1), "Ein Ordner");
+        if option_env!("LANGUAGE_ATLAS_DEFAULT") != Some("German") {
+            assert_eq!(lang.folders(5), "5 folders");
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn plural_zero_category() {
+        use crate::plural::{PluralRule, PluralRules};
+
+        enum Language {
+            English,
+            Latvian,
+        }
+
+        impl PluralRules for Language {
+            fn plural_rule(&self) -> PluralRule {
+                match self {
+                    Language::English => PluralRule::English,
+                    Language::Latvian => PluralRule::Latvian,
+                }
+            }
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language
+            files(count: u32) {
+                English.zero:  "No files"
+                English.other: "{count} files"
+                Latvian.zero:  "{count} failu"
+                Latvian.one:   "{count} fails"
+                Latvian.other: "{count} faili"
+            }
+            folders(count: u32) {
+                English.0:     "No folders"
+                English.other: "{count} folders"
+            }
+        }
+
+        assert_eq!(Language::English.files(0), "0 files");
+        assert_eq!(Language::English.folders(0), "No folders");
+        assert_eq!(Language::Latvian.files(0), "0 failu");
+        assert_eq!(Language::Latvian.files(10), "10 failu");
+        assert_eq!(Language::Latvian.files(21), "21 fails");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn plural_numbers_and_categories() {
+        use crate::plural::{PluralRule, PluralRules};
+
+        enum Language {
+            English,
+            Russian,
+        }
+
+        impl PluralRules for Language {
+            fn plural_rule(&self) -> PluralRule {
+                match self {
+                    Language::English => PluralRule::English,
+                    Language::Russian => PluralRule::Russian,
+                }
+            }
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language
+            messages(count: u32) {
+                English.one:   "{count} message"
+                English.other: "{count} messages"
+                English.0:     "No messages"
+                Russian.one:   "{count} сообщение"
+                Russian.few:   "{count} сообщения"
+                Russian.many:  "{count} сообщений"
+                Russian.0:     "Нет сообщений"
+            }
+        }
+
+        assert_eq!(Language::English.messages(0), "No messages");
+        assert_eq!(Language::English.messages(1), "1 message");
+        assert_eq!(Language::English.messages(2), "2 messages");
+        assert_eq!(Language::Russian.messages(0), "Нет сообщений");
+        assert_eq!(Language::Russian.messages(21), "21 сообщение");
+        assert_eq!(Language::Russian.messages(3), "3 сообщения");
+        assert_eq!(Language::Russian.messages(11), "11 сообщений");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn ordinal() {
+        use crate::plural::{OrdinalRule, OrdinalRules};
+
+        enum Language {
+            English,
+            German,
+        }
+
+        impl OrdinalRules for Language {
+            fn ordinal_rule(&self) -> OrdinalRule {
+                match self {
+                    Language::English => OrdinalRule::English,
+                    Language::German => OrdinalRule::NoOrdinal,
+                }
+            }
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language
+            finished ordinal(place: u32) {
+                English.one:   "You finished {place}st"
+                English.two:   "You finished {place}nd"
+                English.few:   "You finished {place}rd"
+                English.other: "You finished {place}th"
+                German:        "Du bist {place}. geworden"
+            }
+        }
+
+        assert_eq!(Language::English.finished(1), "You finished 1st");
+        assert_eq!(Language::English.finished(2), "You finished 2nd");
+        assert_eq!(Language::English.finished(3), "You finished 3rd");
+        assert_eq!(Language::English.finished(4), "You finished 4th");
+        assert_eq!(Language::English.finished(11), "You finished 11th");
+        assert_eq!(Language::English.finished(12), "You finished 12th");
+        assert_eq!(Language::English.finished(21), "You finished 21st");
+        assert_eq!(Language::English.finished(113), "You finished 113th");
+        assert_eq!(Language::German.finished(1), "Du bist 1. geworden");
+    }
+
+    #[test]
+    fn ordinal_rules() {
+        use crate::plural::{OrdinalRule, PluralCategory::*};
+
+        assert_eq!(OrdinalRule::English.ordinal(22), Two);
+        assert_eq!(OrdinalRule::English.ordinal(103), Few);
+        assert_eq!(OrdinalRule::French.ordinal(1), One);
+        assert_eq!(OrdinalRule::French.ordinal(2), Other);
+        assert_eq!(OrdinalRule::Italian.ordinal(11), Many);
+        assert_eq!(OrdinalRule::Swedish.ordinal(22), One);
+        assert_eq!(OrdinalRule::Swedish.ordinal(12), Other);
+        assert_eq!(OrdinalRule::Hungarian.ordinal(5), One);
+        assert_eq!(OrdinalRule::Catalan.ordinal(4), Few);
+        assert_eq!(OrdinalRule::Welsh.ordinal(8), Zero);
+        assert_eq!(OrdinalRule::Welsh.ordinal(6), Many);
+        assert_eq!(OrdinalRule::NoOrdinal.ordinal(1), Other);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn select() {
+        enum Language {
+            English,
+            German,
+        }
+
+        #[derive(Clone, Copy)]
+        #[allow(dead_code)]
+        enum Gender {
+            Female,
+            Male,
+            Other,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language
+            invited select(gender: Gender, name: &str) {
+                English.Female: "{name} invited you to her party"
+                English.Male:   "{name} invited you to his party"
+                English.other:  "{name} invited you to their party"
+                German.Female:  "{name} hat dich zu ihrer Party eingeladen"
+                German.Male:    "{name} hat dich zu seiner Party eingeladen"
+                German.other:   "{name} hat dich zu einer Party eingeladen"
+            }
+            greeted select(gender: Gender) {
+                English.Female: "She greeted you"
+                English.other:  "They greeted you"
+                German.Female:  "Sie hat dich begrüßt"
+            }
+        }
+
+        assert_eq!(Language::English.invited(Gender::Female, "Ada"), "Ada invited you to her party");
+        assert_eq!(Language::English.invited(Gender::Male, "Alan"), "Alan invited you to his party");
+        assert_eq!(Language::English.invited(Gender::Other, "Sam"), "Sam invited you to their party");
+        assert_eq!(Language::German.invited(Gender::Male, "Alan"), "Alan hat dich zu seiner Party eingeladen");
+        assert_eq!(Language::German.invited(Gender::Other, "Sam"), "Sam hat dich zu einer Party eingeladen");
+        assert_eq!(Language::German.greeted(Gender::Female), "Sie hat dich begrüßt");
+        if option_env!("LANGUAGE_ATLAS_DEFAULT") != Some("German") {
+            assert_eq!(Language::German.greeted(Gender::Male), "They greeted you");
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn method_override() {
+        enum Language {
+            English,
+            German,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Missing: KeyName
+            r#match {
+                English: "Match"
+                German: "Spiel"
+            }
+            type @fn(type_label) {
+                English: "Type"
+            }
+            len @fn(length_label)(n: usize) {
+                English: "{n} characters"
+            }
+            r#loop {
+                English: "Loop"
+            }
+            r#where(place) {
+                English: "Where is {place}?"
+            }
+        }
+
+        assert_eq!(Language::English.r#match(), "Match");
+        assert_eq!(Language::German.r#match(), "Spiel");
+        assert_eq!(Language::English.type_label(), "Type");
+        assert_eq!(Language::German.type_label(), "[type]");
+        assert_eq!(Language::German.length_label(3), "[len]");
+        assert_eq!(Language::German.r#loop(), "[loop]");
+        assert_eq!(Language::German.r#where("Bonn"), "[where]");
+
+        generate_language_schema! {
+            trait Labels {
+                type @fn(type_label)
+            }
+        }
+
+        enum Other {
+            English,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Other, Schema: Labels
+            type @fn(type_label) {
+                English: "Type"
+            }
+        }
+
+        assert_eq!(Labels::type_label(&Other::English), "Type");
+    }
+
+    #[test]
+    #[allow(dead_code, deprecated)]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn missing_translation_warnings() {
+        enum Language {
+            English,
+            German,
+            Spanish,
+            French,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Languages: [English, German, Spanish, French]
+            greeting {
+                English: "Hello"
+                German:  "Hallo"
+                Spanish: "Hola"
+            }
+            farewell(name) {
+                English: "Goodbye, {name}"
+                German:  "Auf Wiedersehen, {name}"
+            }
+            files(count: u32) {
+                English.1:     "One file"
+                English.other: "{count} files"
+                Spanish.1:     "Un archivo"
+                Spanish.other: "{count} archivos"
+            }
+            type @fn(type_label) {
+                English: "Type"
+                German:  "Typ"
+                Spanish: "Tipo"
+            }
+            dummy { }
+        }
+
+        assert_eq!(Language::German.greeting(), "Hallo");
+        assert_eq!(Language::German.farewell("Ada"), "Auf Wiedersehen, Ada");
+        assert_eq!(Language::German.type_label(), "Typ");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn missing_policy_strict() {
+        enum Language {
+            English,
+            German,
+        }
+
+        enum Gender {
+            Female,
+            Male,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Missing: Strict
+            greeting {
+                German:  "Hallo"
+                English: "Hello"
+            }
+            farewell(name) {
+                English: "Goodbye, {name}"
+                German:  "Auf Wiedersehen, {name}"
+            }
+            files(count: u32) {
+                English.1:     "One file"
+                English.other: "{count} files"
+                German:        "{count} Datei(en)"
+            }
+            invited select(gender: Gender) {
+                English.other: "They are invited"
+                German.Female: "Sie ist eingeladen"
+                German.other:  "Eingeladen"
+            }
+        }
+
+        assert_eq!(Language::English.greeting(), "Hello");
+        assert_eq!(Language::German.greeting(), "Hallo");
+        assert_eq!(Language::German.farewell("Ada"), "Auf Wiedersehen, Ada");
+        assert_eq!(Language::English.files(1), "One file");
+        assert_eq!(Language::German.files(2), "2 Datei(en)");
+        assert_eq!(Language::German.invited(Gender::Female), "Sie ist eingeladen");
+        assert_eq!(Language::German.invited(Gender::Male), "Eingeladen");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn default_marker() {
+        enum Language {
+            English,
+            German,
+            Spanish,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language
+            greeting {
+                German:          "Hallo"
+                default English: "Hello"
+            }
+            farewell(name) {
+                German:          "Auf Wiedersehen, {name}"
+                default English: "Goodbye, {name}"
+            }
+            files(count: usize) {
+                German.1:              "Eine Datei"
+                German.other:          "{count} Dateien"
+                English.1:             "One file"
+                default English.other: "{count} files"
+            }
+        }
+
+        assert_eq!(Language::German.greeting(), "Hallo");
+        assert_eq!(Language::Spanish.greeting(), "Hello");
+        assert_eq!(Language::Spanish.farewell("Ada"), "Goodbye, Ada");
+        assert_eq!(Language::English.files(1), "One file");
+        assert_eq!(Language::Spanish.files(1), "One file");
+        assert_eq!(Language::Spanish.files(3), "3 files");
+        assert_eq!(Language::German.files(3), "3 Dateien");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn header_default() {
+        enum Language {
+            English,
+            German,
+            French,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Default: English
+            greeting {
+                French:  "Bonjour"
+                German:  "Hallo"
+                English: "Hello"
+            }
+            farewell(name) {
+                French:  "Au revoir, {name}"
+                English: "Goodbye, {name}"
+            }
+            files(count: usize) {
+                French.other:  "{count} fichiers"
+                English.1:     "One file"
+                English.other: "{count} files"
+            }
+            thanks {
+                French:         "Merci"
+                default German: "Danke"
+            }
+            title {
+                French: "Titre"
+            }
+        }
+
+        assert_eq!(Language::French.greeting(), "Bonjour");
+        let french_default = option_env!("LANGUAGE_ATLAS_DEFAULT") == Some("French")
+            && !cfg!(feature = "default-lang-only");
+        if french_default {
+            assert_eq!(Language::German.farewell("Ada"), "Au revoir, Ada");
+            assert_eq!(Language::German.files(2), "2 fichiers");
+        } else {
+            assert_eq!(Language::German.farewell("Ada"), "Goodbye, Ada");
+            assert_eq!(Language::German.files(1), "One file");
+        }
+        assert_eq!(Language::English.thanks(), "Danke");
+        assert_eq!(Language::English.title(), "Titre");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn header_order() {
+        enum Language {
+            English,
+            German,
+            French,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Missing: KeyName, Fallback: [French -> German], Default: English
+            greeting {
+                German:  "Hallo"
+                English: "Hello"
+            }
+            title {
+                French: "Titre"
+            }
+        }
+
+        assert_eq!(Language::French.greeting(), "Hallo");
+        assert_eq!(Language::German.greeting(), "Hallo");
+        assert_eq!(Language::English.title(), "[title]");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn fallback_chains() {
+        enum Language {
+            English,
+            Portuguese,
+            PortugueseBrazil,
+            German,
+            SwissGerman,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Fallback: [PortugueseBrazil -> Portuguese -> English, SwissGerman -> German]
+            greeting {
+                English:    "Hello"
+                Portuguese: "Olá"
+                German:     "Hallo"
+            }
+            farewell(name) {
+                English: "Goodbye, {name}"
+                German:  "Auf Wiedersehen, {name}"
+            }
+            files(count: usize) {
+                English.1:        "One file"
+                English.other:    "{count} files"
+                Portuguese.1:     "Um arquivo"
+                Portuguese.other: "{count} arquivos"
+            }
+        }
+
+        assert_eq!(Language::PortugueseBrazil.greeting(), "Olá");
+        assert_eq!(Language::SwissGerman.greeting(), "Hallo");
+        assert_eq!(Language::PortugueseBrazil.farewell("Ana"), "Goodbye, Ana");
+        assert_eq!(Language::SwissGerman.farewell("Ada"), "Auf Wiedersehen, Ada");
+        assert_eq!(Language::PortugueseBrazil.files(2), "2 arquivos");
+        assert_eq!(Language::SwissGerman.files(2), "2 files");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn fallback_chains_missing_policy() {
+        enum Language {
+            English,
+            German,
+            SwissGerman,
+            Austrian,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Fallback: [SwissGerman -> German, Austrian -> SwissGerman -> Austrian], Missing: KeyName
+            greeting {
+                English: "Hello"
+                German:  "Hallo"
+            }
+            farewell {
+                English: "Goodbye"
+            }
+        }
+
+        assert_eq!(Language::SwissGerman.greeting(), "Hallo");
+        assert_eq!(Language::Austrian.greeting(), "Hallo");
+        assert_eq!(Language::SwissGerman.farewell(), "[farewell]");
+        assert_eq!(Language::Austrian.farewell(), "[farewell]");
+        assert_eq!(Language::English.farewell(), "Goodbye");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn generated_enum() {
+        generate_language_functions! {
+            LanguageEnum: Language, Languages: enum [English, Spanish, French, German]
+            greeting {
+                English: "Hello"
+                Spanish: "Hola"
+            }
+        }
+
+        let lang = Language::French;
+        assert_eq!(lang, Language::French);
+        assert_eq!(format!("{:?}", lang), "French");
+        if option_env!("LANGUAGE_ATLAS_DEFAULT") != Some("Spanish") {
+            assert_eq!(lang.greeting(), "Hello");
+            assert_eq!(Language::German.greeting(), "Hello");
+        }
+        assert_eq!(Language::Spanish.greeting(), "Hola");
+        assert_eq!(Language::English.greeting(), "Hello");
+    }
+
+    #[test]
+    fn language_metadata() {
+        generate_language_functions! {
+            LanguageEnum: Language, Languages: enum [
+                English("en", "English", "English"),
+                Spanish("es", "Spanish", "Español"),
+                German("de", "German", "Deutsch"),
+                French("fr", "French", "Français"),
+            ]
+            greeting {
+                English: "Hello"
+                Spanish: "Hola"
+                German:  "Hallo"
+                French:  "Bonjour"
+            }
+        }
+
+        assert_eq!(Language::Spanish.code(), "es");
+        assert_eq!(Language::Spanish.english_name(), "Spanish");
+        assert_eq!(Language::Spanish.native_name(), "Español");
+        assert_eq!(Language::German.native_name(), "Deutsch");
+        assert_eq!(Language::French.code(), "fr");
+        assert_eq!(Language::English.greeting(), "Hello");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn lazy_parameter() {
+        use crate::Lazy;
+        use std::cell::Cell;
+
+        enum Language {
+            English,
+            German,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language
+            saved(name, details) {
+                English: "Saved {name} ({details})"
+                German:  "{name} gespeichert"
+            }
+        }
+
+        let calls = Cell::new(0);
+        let details = || {
+            calls.set(calls.get() + 1);
+            "3 files"
+        };
+
+        assert_eq!(Language::German.saved("notes", Lazy(details)), "notes gespeichert");
+        assert_eq!(calls.get(), 0);
+        assert_eq!(Language::English.saved("notes", Lazy(details)), "Saved notes (3 files)");
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn lazy_parameter_repeated() {
+        use crate::Lazy;
+        use std::cell::Cell;
+
+        enum Language {
+            English,
+            German,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language
+            report(details) {
+                English: "{details} (details: {details:>9})"
+                German:  "Bericht"
+            }
+        }
+
+        let calls = Cell::new(0);
+        let details = || {
+            calls.set(calls.get() + 1);
+            "3 files"
+        };
+
+        assert_eq!(Language::English.report(Lazy(details)), "3 files (details:   3 files)");
+        assert_eq!(calls.get(), 1);
+        assert_eq!(Language::German.report(Lazy(details)), "Bericht");
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[allow(dead_code)]
+    enum CoverageLanguage {
+        English,
+        German,
+        Spanish,
+    }
+
+    generate_language_functions! {
+        LanguageEnum: CoverageLanguage, Coverage: coverage { English: 100, German: 66 }
+        greeting {
+            English: "Hello"
+            German:  "Hallo"
+        }
+        farewell(name) {
+            English: "Goodbye, {name}"
+            German:  "Auf Wiedersehen, {name}"
+        }
+        files(count: u32) {
+            English.1:     "One file"
+            English.other: "{count} files"
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "default-lang-only", ignore)]
+    fn coverage_strings() {
+        assert_eq!(CoverageLanguage::German.greeting(), "Hallo");
+        assert_eq!(CoverageLanguage::German.farewell("Ada"), "Auf Wiedersehen, Ada");
+        assert_eq!(CoverageLanguage::German.files(2), "2 files");
+    }
+
+    #[test]
+    #[allow(dead_code)]
+    fn key_reference() {
+        enum Language {
+            English,
+            German,
+        }
+
+        enum Gender {
+            Female,
+            Other,
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Default: English, Reference: key_reference
+            greeting {
+                German:  "Hallo"
+                English: "Hello"
+            }
+            farewell(name) {
+                default German: "Auf Wiedersehen, {name}"
+                English:        "Goodbye, {name} | see you"
+            }
+            date(day: u8, month: u8) {
+                English: "{month}/{day}"
+            }
+            r#type { }
+            choice {
+                English: "Yes | No"
+            }
+            files(count: u32) {
+                German.other:  "{count} Dateien"
+                English.1:     "One file"
+                English.other: "{count} files"
+            }
+            invited select(gender: Gender) {
+                English.Female: "She is invited"
+                English.other:  "They are invited"
+            }
+        }
+
+        let expected = match option_env!("LANGUAGE_ATLAS_DEFAULT") {
+            Some("German") if !cfg!(feature = "default-lang-only") => "Hallo",
+            _ => "Hello",
+        };
+        let files = match option_env!("LANGUAGE_ATLAS_DEFAULT") {
+            Some("German") if !cfg!(feature = "default-lang-only") => "`other`: {count} Dateien",
+            _ => "`1`: One file<br>`other`: {count} files",
+        };
+        assert_eq!(Language::key_reference(), format!(
+            "| Key | Parameters | Default |\n\
+             | --- | --- | --- |\n\
+             | `greeting` |  | {expected} |\n\
+             | `farewell` | `name` | Auf Wiedersehen, {{name}} |\n\
+             | `date` | `day: u8, month: u8` | {{month}}/{{day}} |\n\
+             | `type` |  |  |\n\
+             | `choice` |  | Yes \\| No |\n\
+             | `files` | `count: u32` | {files} |\n\
+             | `invited` | `gender: Gender` | `Female`: She is invited<br>`other`: They are invited |\n",
+        ));
+    }
+
+    #[test]
+    fn atlas_format() {
+        use crate::plural::{PluralRule, PluralRules};
+        use crate::AtlasFormat;
+
+        struct UserId(u64);
+
+        impl AtlasFormat for UserId {
+            fn atlas_fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "#{}…", self.0 % 1000)
+            }
+        }
+
+        generate_language_schema! {
+            trait Users {
+                owner(user)
+            }
+        }
+
+        enum Language {
+            English,
+        }
+
+        impl PluralRules for Language {
+            fn plural_rule(&self) -> PluralRule {
+                PluralRule::English
+            }
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language
+            blocked(user) {
+                English: "User {user} is blocked"
+            }
+            invites(count, user) {
+                English.one:   "{user} has one invite"
+                English.other: "{user} has {count} invites"
+            }
+        }
+
+        generate_language_functions! {
+            LanguageEnum: Language, Schema: Users
+            owner(user) {
+                English: "Owned by {user}"
+            }
+        }
+
+        assert_eq!(Language::English.blocked(UserId(123456)), "User #456… is blocked");
+        assert_eq!(Language::English.blocked("Ada"), "User Ada is blocked");
+        assert_eq!(Language::English.invites(3, UserId(7)), "#7… has 3 invites");
+        assert_eq!(Users::owner(&Language::English, UserId(42)), "Owned by #42…");
+    }
+
+    #[test]
+    fn coverage_check() {
+        assert_eq!(crate::__check_coverage("English", 3, &[], 100), Ok(()));
+        assert_eq!(crate::__check_coverage("German", 3, &["title"], 66), Ok(()));
+        assert_eq!(crate::__check_coverage("German", 0, &[], 100), Ok(()));
+        assert_eq!(
+            crate::__check_coverage("German", 3, &["title"], 90),
+            Err(String::from("German covers 66% of the keys, expected at least 90%. Missing: [\"title\"]")),
+        );
+    }
+
+    #[test]
+    fn placeholder_check() {
+        use crate::__uses_parameters;
+
+        assert!(__uses_parameters("Goodbye, {name}", &["name"]));
+        assert!(__uses_parameters("{name:>width$} {{nmae}} {name:?}", &["name", "width"]));
+        assert!(__uses_parameters("{ count } files, {count:.precision$}", &["count", "precision"]));
+        assert!(__uses_parameters("{type}", &["r#type"]));
+        assert!(__uses_parameters("}} {0}", &[]));
+        assert!(!__uses_parameters("Goodbye, {nmae}", &["name"]));
+        assert!(!__uses_parameters("Goodbye, {NAME}", &["name"]));
+        assert!(!__uses_parameters("{name:>widht$}", &["name", "width"]));
+        assert!(!__uses_parameters("{names}", &["name"]));
+    }
+}